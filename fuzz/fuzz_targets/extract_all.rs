@@ -0,0 +1,10 @@
+#![no_main]
+
+use jp_prefecture::prefectures;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|s: &str| {
+    for (prefecture, range) in prefectures::extract_all(s) {
+        assert!(prefecture.kanji().starts_with(&s[range]));
+    }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use jp_prefecture::prefectures;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|s: &str| {
+    let _ = prefectures::find(s);
+    let _ = prefectures::parse(s);
+});
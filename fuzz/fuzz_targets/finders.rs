@@ -0,0 +1,22 @@
+#![no_main]
+
+use jp_prefecture::prefectures;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|s: &str| {
+    let _ = prefectures::find_by_code_str(s);
+    let _ = prefectures::find_by_iso_code(s);
+    let _ = prefectures::find_by_kanji(s);
+    let _ = prefectures::find_by_hiragana(s);
+    let _ = prefectures::find_by_katakana(s);
+    let _ = prefectures::find_by_english(s);
+    let _ = prefectures::find_by_slug(s);
+    let _ = prefectures::find_by_zh_hans(s);
+    let _ = prefectures::find_by_zh_hant(s);
+    let _ = prefectures::find_by_ko(s);
+    let _ = prefectures::find_all(s.split(','));
+    let _ = prefectures::search_prefix(s);
+    let _ = prefectures::search_fuzzy(s);
+    let _ = prefectures::find_lenient(s);
+    let _ = prefectures::cmp_by_code(s, "東京都");
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use jp_prefecture::prefectures::Prefecture;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|s: &str| {
+    if let Ok(prefecture) = s.parse::<Prefecture>() {
+        // Whatever parses must round-trip through its canonical name
+        assert_eq!(prefecture.kanji().parse::<Prefecture>(), Ok(prefecture));
+    }
+});
@@ -0,0 +1,259 @@
+//! ordinance-designated cities (政令指定都市)
+//!
+//! # Examples
+//!
+//! ```
+//! use jp_prefecture::designated_cities::DesignatedCity;
+//! use jp_prefecture::prefectures::Prefecture;
+//!
+//! let yokohama = DesignatedCity::Yokohama;
+//!
+//! assert_eq!(yokohama.prefecture(), Prefecture::Kanagawa);
+//! assert_eq!(yokohama.kanji(), "横浜市");
+//! # #[cfg(feature = "kana")]
+//! assert_eq!(yokohama.hiragana(), "よこはまし");
+//! # #[cfg(feature = "kana")]
+//! assert_eq!(yokohama.katakana(), "ヨコハマシ");
+//! # #[cfg(feature = "english")]
+//! assert_eq!(yokohama.english(), "Yokohama");
+//!
+//! assert_eq!(
+//!     Prefecture::Kanagawa.designated_cities(),
+//!     vec![DesignatedCity::Yokohama, DesignatedCity::Kawasaki, DesignatedCity::Sagamihara]
+//! );
+//! ```
+
+use crate::mapping::{DesignatedCityData, DESIGNATED_CITY_TABLE};
+use crate::prefectures::Prefecture;
+
+/// A value of ordinance-designated city
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum DesignatedCity {
+    Sapporo,
+    Sendai,
+    Saitama,
+    Chiba,
+    Yokohama,
+    Kawasaki,
+    Sagamihara,
+    Niigata,
+    Shizuoka,
+    Hamamatsu,
+    Nagoya,
+    Kyoto,
+    Osaka,
+    Sakai,
+    Kobe,
+    Okayama,
+    Hiroshima,
+    Kitakyushu,
+    Fukuoka,
+    Kumamoto,
+}
+
+impl DesignatedCity {
+    /// Number of designated cities
+    pub const COUNT: usize = 20;
+
+    /// All designated cities, ordered by local government code
+    pub const VARIANTS: [DesignatedCity; DesignatedCity::COUNT] = [
+        DesignatedCity::Sapporo,
+        DesignatedCity::Sendai,
+        DesignatedCity::Saitama,
+        DesignatedCity::Chiba,
+        DesignatedCity::Yokohama,
+        DesignatedCity::Kawasaki,
+        DesignatedCity::Sagamihara,
+        DesignatedCity::Niigata,
+        DesignatedCity::Shizuoka,
+        DesignatedCity::Hamamatsu,
+        DesignatedCity::Nagoya,
+        DesignatedCity::Kyoto,
+        DesignatedCity::Osaka,
+        DesignatedCity::Sakai,
+        DesignatedCity::Kobe,
+        DesignatedCity::Okayama,
+        DesignatedCity::Hiroshima,
+        DesignatedCity::Kitakyushu,
+        DesignatedCity::Fukuoka,
+        DesignatedCity::Kumamoto,
+    ];
+
+    /// Return the prefecture the city belongs to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::designated_cities::DesignatedCity;
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(DesignatedCity::Sakai.prefecture(), Prefecture::Osaka);
+    /// ```
+    pub fn prefecture(&self) -> Prefecture {
+        self.data().prefecture
+    }
+
+    /// Return a city name in kanji
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::designated_cities::DesignatedCity;
+    ///
+    /// assert_eq!(DesignatedCity::Sakai.kanji(), "堺市".to_string());
+    /// ```
+    pub fn kanji(&self) -> String {
+        self.data().kanji.to_string()
+    }
+
+    /// Return a city name in hiragana
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::designated_cities::DesignatedCity;
+    ///
+    /// assert_eq!(DesignatedCity::Sakai.hiragana(), "さかいし".to_string());
+    /// ```
+    #[cfg(feature = "kana")]
+    pub fn hiragana(&self) -> String {
+        self.data().hiragana.to_string()
+    }
+
+    /// Return a city name in katakana
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::designated_cities::DesignatedCity;
+    ///
+    /// assert_eq!(DesignatedCity::Sakai.katakana(), "サカイシ".to_string());
+    /// ```
+    #[cfg(feature = "kana")]
+    pub fn katakana(&self) -> String {
+        self.data().katakana.to_string()
+    }
+
+    /// Return a city name in english
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::designated_cities::DesignatedCity;
+    ///
+    /// assert_eq!(DesignatedCity::Sakai.english(), "Sakai".to_string());
+    /// ```
+    #[cfg(feature = "english")]
+    pub fn english(&self) -> String {
+        self.data().english.to_string()
+    }
+
+    fn data(&self) -> &'static DesignatedCityData {
+        DESIGNATED_CITY_TABLE
+            .iter()
+            .find(|data| data.city == *self)
+            .expect("Unexpected error")
+    }
+}
+
+impl Prefecture {
+    /// Return the ordinance-designated cities in a prefecture, ordered by local government code
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::designated_cities::DesignatedCity;
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(
+    ///     Prefecture::Fukuoka.designated_cities(),
+    ///     vec![DesignatedCity::Kitakyushu, DesignatedCity::Fukuoka]
+    /// );
+    /// assert_eq!(Prefecture::Tokyo.designated_cities(), vec![]);
+    /// ```
+    pub fn designated_cities(&self) -> Vec<DesignatedCity> {
+        DESIGNATED_CITY_TABLE
+            .iter()
+            .filter(|data| data.prefecture == *self)
+            .map(|data| data.city)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(DesignatedCity::Sapporo => Prefecture::Hokkaido)]
+    #[test_case(DesignatedCity::Sendai => Prefecture::Miyagi)]
+    #[test_case(DesignatedCity::Saitama => Prefecture::Saitama)]
+    #[test_case(DesignatedCity::Chiba => Prefecture::Chiba)]
+    #[test_case(DesignatedCity::Yokohama => Prefecture::Kanagawa)]
+    #[test_case(DesignatedCity::Kawasaki => Prefecture::Kanagawa)]
+    #[test_case(DesignatedCity::Sagamihara => Prefecture::Kanagawa)]
+    #[test_case(DesignatedCity::Niigata => Prefecture::Niigata)]
+    #[test_case(DesignatedCity::Shizuoka => Prefecture::Shizuoka)]
+    #[test_case(DesignatedCity::Hamamatsu => Prefecture::Shizuoka)]
+    #[test_case(DesignatedCity::Nagoya => Prefecture::Aichi)]
+    #[test_case(DesignatedCity::Kyoto => Prefecture::Kyoto)]
+    #[test_case(DesignatedCity::Osaka => Prefecture::Osaka)]
+    #[test_case(DesignatedCity::Sakai => Prefecture::Osaka)]
+    #[test_case(DesignatedCity::Kobe => Prefecture::Hyogo)]
+    #[test_case(DesignatedCity::Okayama => Prefecture::Okayama)]
+    #[test_case(DesignatedCity::Hiroshima => Prefecture::Hiroshima)]
+    #[test_case(DesignatedCity::Kitakyushu => Prefecture::Fukuoka)]
+    #[test_case(DesignatedCity::Fukuoka => Prefecture::Fukuoka)]
+    #[test_case(DesignatedCity::Kumamoto => Prefecture::Kumamoto)]
+    fn prefecture_tests(city: DesignatedCity) -> Prefecture {
+        city.prefecture()
+    }
+
+    #[test_case(DesignatedCity::Sapporo => String::from("札幌市"))]
+    #[test_case(DesignatedCity::Sendai => String::from("仙台市"))]
+    #[test_case(DesignatedCity::Saitama => String::from("さいたま市"))]
+    #[test_case(DesignatedCity::Chiba => String::from("千葉市"))]
+    #[test_case(DesignatedCity::Yokohama => String::from("横浜市"))]
+    #[test_case(DesignatedCity::Kawasaki => String::from("川崎市"))]
+    #[test_case(DesignatedCity::Sagamihara => String::from("相模原市"))]
+    #[test_case(DesignatedCity::Niigata => String::from("新潟市"))]
+    #[test_case(DesignatedCity::Shizuoka => String::from("静岡市"))]
+    #[test_case(DesignatedCity::Hamamatsu => String::from("浜松市"))]
+    #[test_case(DesignatedCity::Nagoya => String::from("名古屋市"))]
+    #[test_case(DesignatedCity::Kyoto => String::from("京都市"))]
+    #[test_case(DesignatedCity::Osaka => String::from("大阪市"))]
+    #[test_case(DesignatedCity::Sakai => String::from("堺市"))]
+    #[test_case(DesignatedCity::Kobe => String::from("神戸市"))]
+    #[test_case(DesignatedCity::Okayama => String::from("岡山市"))]
+    #[test_case(DesignatedCity::Hiroshima => String::from("広島市"))]
+    #[test_case(DesignatedCity::Kitakyushu => String::from("北九州市"))]
+    #[test_case(DesignatedCity::Fukuoka => String::from("福岡市"))]
+    #[test_case(DesignatedCity::Kumamoto => String::from("熊本市"))]
+    fn kanji_tests(city: DesignatedCity) -> String {
+        city.kanji()
+    }
+
+    #[test_case(Prefecture::Hokkaido => vec![DesignatedCity::Sapporo])]
+    #[test_case(Prefecture::Kanagawa => vec![DesignatedCity::Yokohama, DesignatedCity::Kawasaki, DesignatedCity::Sagamihara])]
+    #[test_case(Prefecture::Shizuoka => vec![DesignatedCity::Shizuoka, DesignatedCity::Hamamatsu])]
+    #[test_case(Prefecture::Osaka => vec![DesignatedCity::Osaka, DesignatedCity::Sakai])]
+    #[test_case(Prefecture::Tokyo => Vec::<DesignatedCity>::new())]
+    #[test_case(Prefecture::Okinawa => Vec::<DesignatedCity>::new())]
+    fn designated_cities_tests(prefecture: Prefecture) -> Vec<DesignatedCity> {
+        prefecture.designated_cities()
+    }
+
+    #[test]
+    fn variants_tests() {
+        assert_eq!(DesignatedCity::VARIANTS.len(), DESIGNATED_CITY_TABLE.len());
+        assert_eq!(
+            Prefecture::VARIANTS
+                .iter()
+                .map(|pref| pref.designated_cities().len())
+                .sum::<usize>(),
+            DesignatedCity::COUNT
+        );
+    }
+}
@@ -0,0 +1,462 @@
+//! reusable prefecture finder
+//!
+//! A [`PrefectureFinder`] builds its name index once from [`FinderOptions`] and can then
+//! be shared across threads to parse large streams of input. Successful lookups do not
+//! allocate.
+//!
+//! # Examples
+//!
+//! ```
+//! use jp_prefecture::finder::{FinderOptions, PrefectureFinder, Script};
+//! use jp_prefecture::prefectures::Prefecture;
+//!
+//! let finder = PrefectureFinder::new(
+//!     FinderOptions::new()
+//!         .scripts(&[Script::Kanji, Script::Katakana])
+//!         .lenient(true),
+//! );
+//!
+//! assert_eq!(finder.find("東京都"), Ok(Prefecture::Tokyo));
+//! # #[cfg(feature = "kana")]
+//! assert_eq!(finder.find("トーキョー"), Ok(Prefecture::Tokyo));
+//! assert!(finder.find("tokyo").is_err());
+//! ```
+
+use std::collections::HashMap;
+
+#[cfg(feature = "kana")]
+use crate::prefectures::fold_kana_chars;
+#[cfg(feature = "english")]
+use crate::prefectures::fold_romaji_chars;
+use crate::prefectures::{check_len, unique_match, Prefecture, DEFAULT_MAX_INPUT_LEN, TABLE};
+use crate::Error;
+
+/// Writing systems a [`PrefectureFinder`] can match names in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum Script {
+    /// Names in kanji (e.g. "東京都")
+    Kanji,
+    /// Names in hiragana (e.g. "とうきょうと")
+    Hiragana,
+    /// Names in katakana (e.g. "トウキョウト")
+    Katakana,
+    /// Names in Hepburn or Kunrei-shiki romaji (e.g. "Tokyo", "Tôkyô")
+    English,
+}
+
+impl Script {
+    /// The number of scripts
+    pub const COUNT: usize = 4;
+
+    /// All scripts
+    pub const VARIANTS: [Script; Script::COUNT] = [
+        Script::Kanji,
+        Script::Hiragana,
+        Script::Katakana,
+        Script::English,
+    ];
+}
+
+/// Options for building a [`PrefectureFinder`]
+///
+/// By default every script is accepted, names may omit the 都/道/府/県 suffix, lenient
+/// normalization is off and lenient inputs may be up to [`DEFAULT_MAX_INPUT_LEN`] bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinderOptions {
+    scripts: Vec<Script>,
+    short_names: bool,
+    lenient: bool,
+    max_input_len: usize,
+}
+
+impl FinderOptions {
+    /// Return the default options
+    pub fn new() -> FinderOptions {
+        FinderOptions {
+            scripts: Script::VARIANTS.to_vec(),
+            short_names: true,
+            lenient: false,
+            max_input_len: DEFAULT_MAX_INPUT_LEN,
+        }
+    }
+
+    /// Only match names written in the given scripts
+    pub fn scripts(mut self, scripts: &[Script]) -> FinderOptions {
+        self.scripts = scripts.to_vec();
+        self
+    }
+
+    /// Whether names without the 都/道/府/県 suffix (e.g. "東京") are accepted
+    pub fn short_names(mut self, short_names: bool) -> FinderOptions {
+        self.short_names = short_names;
+        self
+    }
+
+    /// Whether casual kana spellings and alternative romaji are accepted
+    ///
+    /// Kana is normalized as in [`find_lenient`](crate::prefectures::find_lenient) and
+    /// romaji as in [`find_by_english`](crate::prefectures::find_by_english).
+    /// Folding stays within a script, so a katakana-only finder does not accept casual
+    /// spellings in hiragana.
+    pub fn lenient(mut self, lenient: bool) -> FinderOptions {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Maximum length, in bytes, of inputs that are normalized in lenient mode
+    ///
+    /// Longer inputs are rejected with [`Error::InputTooLong`] when no exact match is found.
+    pub fn max_input_len(mut self, max_input_len: usize) -> FinderOptions {
+        self.max_input_len = max_input_len;
+        self
+    }
+}
+
+impl Default for FinderOptions {
+    fn default() -> FinderOptions {
+        FinderOptions::new()
+    }
+}
+
+/// A prefecture name index built once from [`FinderOptions`]
+#[derive(Debug, Clone)]
+pub struct PrefectureFinder {
+    names: HashMap<&'static str, Vec<Prefecture>>,
+    romaji: Vec<(&'static str, Prefecture)>,
+    folded: Vec<(Script, String, Prefecture)>,
+    scripts: Vec<Script>,
+    lenient: bool,
+    max_input_len: usize,
+}
+
+impl PrefectureFinder {
+    /// Build a finder from options
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::finder::{FinderOptions, PrefectureFinder};
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// let finder = PrefectureFinder::new(FinderOptions::new().short_names(false));
+    ///
+    /// assert_eq!(finder.find("東京都"), Ok(Prefecture::Tokyo));
+    /// assert!(finder.find("東京").is_err());
+    /// ```
+    pub fn new(options: FinderOptions) -> PrefectureFinder {
+        let accepts = |script| options.scripts.contains(&script);
+        let mut names = HashMap::new();
+        let mut insert = |name, pref| {
+            names.entry(name).or_insert_with(Vec::new).push(pref);
+        };
+        #[allow(unused_mut)]
+        let mut romaji = Vec::new();
+        #[allow(unused_mut)]
+        let mut folded = Vec::new();
+        for data in TABLE.iter() {
+            let pref = data.prefecture;
+            let all = pref.names();
+            if accepts(Script::Kanji) {
+                insert(all.kanji, pref);
+                if options.short_names {
+                    insert(all.kanji_short, pref);
+                }
+            }
+            #[cfg(feature = "kana")]
+            for (script, full, short) in [
+                (Script::Hiragana, all.hiragana, all.hiragana_short),
+                (Script::Katakana, all.katakana, all.katakana_short),
+            ] {
+                if !accepts(script) {
+                    continue;
+                }
+                let forms = if options.short_names {
+                    vec![full, short]
+                } else {
+                    vec![full]
+                };
+                for name in forms {
+                    insert(name, pref);
+                    if options.lenient {
+                        folded.push((script, fold_kana_chars(name).collect(), pref));
+                    }
+                }
+            }
+            #[cfg(feature = "english")]
+            if accepts(Script::English) {
+                for name in [data.english, data.kunrei] {
+                    romaji.push((name, pref));
+                    if options.lenient {
+                        folded.push((Script::English, fold_romaji_chars(name).collect(), pref));
+                    }
+                }
+            }
+        }
+        PrefectureFinder {
+            names,
+            romaji,
+            folded,
+            scripts: options.scripts,
+            lenient: options.lenient,
+            max_input_len: options.max_input_len,
+        }
+    }
+
+    /// Find a prefecture by name
+    ///
+    /// A name of a prefecture written in a script the finder does not accept is rejected
+    /// with [`Error::UnsupportedScript`], and a name matching more than one prefecture
+    /// with [`Error::Ambiguous`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::finder::{FinderOptions, PrefectureFinder, Script};
+    /// use jp_prefecture::{prefectures::Prefecture, Error};
+    ///
+    /// let finder = PrefectureFinder::default();
+    ///
+    /// # #[cfg(feature = "kana")]
+    /// assert_eq!(finder.find("とうきょう"), Ok(Prefecture::Tokyo));
+    /// # #[cfg(feature = "english")]
+    /// assert_eq!(finder.find("TOKYO"), Ok(Prefecture::Tokyo));
+    /// assert_eq!(finder.find("none"), Err(Error::InvalidPrefectureName { name: "none".to_string() }));
+    ///
+    /// let finder = PrefectureFinder::new(FinderOptions::new().scripts(&[Script::Kanji]));
+    ///
+    /// # #[cfg(feature = "english")]
+    /// assert_eq!(
+    ///     finder.find("Tokyo"),
+    ///     Err(Error::UnsupportedScript { name: "Tokyo".to_string(), script: Script::English })
+    /// );
+    /// ```
+    pub fn find<T: AsRef<str>>(&self, s: T) -> Result<Prefecture, Error> {
+        let s = s.as_ref();
+        if let Some(prefs) = self.names.get(s) {
+            return unique_match(s, prefs.iter().copied());
+        }
+        if let Some((_, pref)) = self.romaji.iter().find(|(name, _)| eq_ignore_case(name, s)) {
+            return Ok(*pref);
+        }
+        if self.lenient {
+            check_len(s, self.max_input_len)?;
+            let mut matches = self
+                .folded
+                .iter()
+                .filter(|(script, name, _)| folds_to(s, *script, name))
+                .map(|(_, _, pref)| *pref)
+                .peekable();
+            if matches.peek().is_some() {
+                return unique_match(s, matches);
+            }
+        }
+        match script_of(s).filter(|script| !self.scripts.contains(script)) {
+            Some(script) => Err(Error::UnsupportedScript {
+                name: s.to_string(),
+                script,
+            }),
+            None => Err(Error::InvalidPrefectureName {
+                name: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Compare romaji case-insensitively, including letters with a circumflex
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .eq(b.chars().flat_map(char::to_lowercase))
+}
+
+/// The script `s` is written in, if it is a name of any prefecture
+fn script_of(s: &str) -> Option<Script> {
+    TABLE.iter().find_map(|data| {
+        let names = data.prefecture.names();
+        if s == names.kanji || s == names.kanji_short {
+            return Some(Script::Kanji);
+        }
+        #[cfg(feature = "kana")]
+        if s == names.hiragana || s == names.hiragana_short {
+            return Some(Script::Hiragana);
+        }
+        #[cfg(feature = "kana")]
+        if s == names.katakana || s == names.katakana_short {
+            return Some(Script::Katakana);
+        }
+        #[cfg(feature = "english")]
+        if eq_ignore_case(data.english, s) || eq_ignore_case(data.kunrei, s) {
+            return Some(Script::English);
+        }
+        None
+    })
+}
+
+/// Whether `s` is written in `script` and folds to a name indexed for that script
+fn folds_to(s: &str, script: Script, folded: &str) -> bool {
+    match script {
+        #[cfg(feature = "kana")]
+        Script::Hiragana => {
+            s.chars().all(|c| matches!(c, 'ぁ'..='ゖ' | 'ー'))
+                && fold_kana_chars(s).eq(folded.chars())
+        }
+        #[cfg(feature = "kana")]
+        Script::Katakana => {
+            s.chars().all(|c| matches!(c, 'ァ'..='ヶ' | 'ー'))
+                && fold_kana_chars(s).eq(folded.chars())
+        }
+        #[cfg(feature = "english")]
+        Script::English => fold_romaji_chars(s).eq(folded.chars()),
+        _ => {
+            let _ = (s, folded);
+            false
+        }
+    }
+}
+
+impl Default for PrefectureFinder {
+    fn default() -> PrefectureFinder {
+        PrefectureFinder::new(FinderOptions::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test_case("東京都" => Ok(Prefecture::Tokyo))]
+    #[test_case("東京" => Ok(Prefecture::Tokyo))]
+    #[test_case("とうきょうと" => Ok(Prefecture::Tokyo))]
+    #[test_case("トウキョウ" => Ok(Prefecture::Tokyo))]
+    #[test_case("tokyo" => Ok(Prefecture::Tokyo))]
+    #[test_case("Tôkyô" => Ok(Prefecture::Tokyo) ; "tokyo in kunrei")]
+    #[test_case("TÔKYÔ" => Ok(Prefecture::Tokyo) ; "tokyo in upper case kunrei")]
+    #[test_case("tôkyô" => Ok(Prefecture::Tokyo) ; "tokyo in lower case kunrei")]
+    #[test_case("Sizuoka" => Ok(Prefecture::Shizuoka))]
+    #[test_case("トーキョー" => Err(Error::InvalidPrefectureName { name: "トーキョー".to_string() }) ; "tokyo with long vowel marks")]
+    #[test_case("" => Err(Error::InvalidPrefectureName { name: "".to_string() }))]
+    fn default_finder_tests(s: &str) -> Result<Prefecture, Error> {
+        PrefectureFinder::default().find(s)
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test_case("トーキョー" => Ok(Prefecture::Tokyo) ; "tokyo with long vowel marks")]
+    #[test_case("ほっかいどー" => Ok(Prefecture::Hokkaido) ; "hokkaido with long vowel mark")]
+    #[test_case("Ohsaka" => Ok(Prefecture::Osaka))]
+    #[test_case("Gumma" => Ok(Prefecture::Gunma))]
+    #[test_case("東京" => Ok(Prefecture::Tokyo) ; "kanji")]
+    #[test_case("ー" => Err(Error::InvalidPrefectureName { name: "ー".to_string() }) ; "long vowel mark only")]
+    fn lenient_finder_tests(s: &str) -> Result<Prefecture, Error> {
+        PrefectureFinder::new(FinderOptions::new().lenient(true)).find(s)
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test_case("東京都" => Ok(Prefecture::Tokyo))]
+    #[test_case("東京" => Err(Error::InvalidPrefectureName { name: "東京".to_string() }))]
+    #[test_case("とうきょうと" => Ok(Prefecture::Tokyo))]
+    #[test_case("とうきょう" => Err(Error::InvalidPrefectureName { name: "とうきょう".to_string() }))]
+    #[test_case("tokyo" => Ok(Prefecture::Tokyo))]
+    fn full_names_finder_tests(s: &str) -> Result<Prefecture, Error> {
+        PrefectureFinder::new(FinderOptions::new().short_names(false)).find(s)
+    }
+
+    #[test_case("東京都" => Ok(Prefecture::Tokyo))]
+    #[test_case("none" => Err(Error::InvalidPrefectureName { name: "none".to_string() }))]
+    fn kanji_finder_tests(s: &str) -> Result<Prefecture, Error> {
+        PrefectureFinder::new(FinderOptions::new().scripts(&[Script::Kanji])).find(s)
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test_case("とうきょうと" => Err(Error::UnsupportedScript { name: "とうきょうと".to_string(), script: Script::Hiragana }))]
+    #[test_case("トウキョウ" => Err(Error::UnsupportedScript { name: "トウキョウ".to_string(), script: Script::Katakana }))]
+    #[test_case("tokyo" => Err(Error::UnsupportedScript { name: "tokyo".to_string(), script: Script::English }))]
+    #[test_case("Tôkyô" => Err(Error::UnsupportedScript { name: "Tôkyô".to_string(), script: Script::English }) ; "tokyo in kunrei")]
+    #[test_case("とーきょー" => Err(Error::InvalidPrefectureName { name: "とーきょー".to_string() }) ; "casual spelling")]
+    fn kanji_finder_unsupported_script_tests(s: &str) -> Result<Prefecture, Error> {
+        PrefectureFinder::new(FinderOptions::new().scripts(&[Script::Kanji])).find(s)
+    }
+
+    #[test]
+    fn short_names_are_unambiguous() {
+        let finder = PrefectureFinder::default();
+        for prefs in finder.names.values() {
+            assert!(prefs.iter().all(|pref| *pref == prefs[0]), "{:?}", prefs);
+        }
+    }
+
+    #[cfg(feature = "kana")]
+    #[test]
+    fn lenient_finder_respects_scripts() {
+        let finder = PrefectureFinder::new(
+            FinderOptions::new()
+                .scripts(&[Script::Katakana])
+                .lenient(true),
+        );
+        assert_eq!(finder.find("トーキョー"), Ok(Prefecture::Tokyo));
+        assert_eq!(
+            finder.find("とーきょー"),
+            Err(Error::InvalidPrefectureName {
+                name: "とーきょー".to_string()
+            })
+        );
+        assert!(finder.find("Tookyoo").is_err());
+
+        let finder = PrefectureFinder::new(
+            FinderOptions::new()
+                .scripts(&[Script::Hiragana])
+                .lenient(true),
+        );
+        assert_eq!(finder.find("とーきょー"), Ok(Prefecture::Tokyo));
+        assert!(finder.find("トーキョー").is_err());
+        assert!(finder.find("とーキョー").is_err());
+    }
+
+    #[cfg(feature = "english")]
+    #[test]
+    fn lenient_romaji_finder_rejects_kana() {
+        let finder = PrefectureFinder::new(
+            FinderOptions::new()
+                .scripts(&[Script::English])
+                .lenient(true),
+        );
+        assert_eq!(finder.find("Tookyoo"), Ok(Prefecture::Tokyo));
+        assert!(finder.find("トーキョー").is_err());
+        assert!(finder.find("とうきょう").is_err());
+    }
+
+    #[cfg(feature = "kana")]
+    #[test]
+    fn lenient_finder_rejects_long_input() {
+        let finder = PrefectureFinder::new(FinderOptions::new().lenient(true));
+        assert_eq!(
+            finder.find("ー".repeat(DEFAULT_MAX_INPUT_LEN)),
+            Err(Error::InputTooLong {
+                len: DEFAULT_MAX_INPUT_LEN * 3,
+                max: DEFAULT_MAX_INPUT_LEN
+            })
+        );
+    }
+
+    #[cfg(feature = "kana")]
+    #[test_case("ー".repeat(400), 1200 => Err(Error::InvalidPrefectureName { name: "ー".repeat(400) }) ; "raised limit")]
+    #[test_case("トーキョー".to_string(), 15 => Ok(Prefecture::Tokyo) ; "at custom limit")]
+    #[test_case("トーキョー".to_string(), 14 => Err(Error::InputTooLong { len: 15, max: 14 }) ; "over custom limit")]
+    #[test_case("トウキョウ".to_string(), 0 => Ok(Prefecture::Tokyo) ; "exact match over limit")]
+    fn lenient_finder_length_tests(s: String, max_len: usize) -> Result<Prefecture, Error> {
+        PrefectureFinder::new(FinderOptions::new().lenient(true).max_input_len(max_len)).find(s)
+    }
+
+    #[test]
+    fn default_finder_agrees_with_find() {
+        let finder = PrefectureFinder::default();
+        for pref in Prefecture::VARIANTS {
+            for name in pref.names().forms() {
+                assert_eq!(finder.find(name), crate::prefectures::find(name));
+            }
+        }
+    }
+}
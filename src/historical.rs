@@ -0,0 +1,290 @@
+//! historical names of today's prefectures
+//!
+//! Names that were replaced or merged away before the current 47 prefectures settled,
+//! with the years they were in use. Archival documents can be resolved with [`find`].
+//!
+//! Besides the three prefectures of Hokkaido, 東京府 and the Ryukyu Kingdom, the Meiji-era
+//! prefectures abolished in the consolidations of 1871 to 1881 are covered when most of
+//! their territory lies in one prefecture today, which is the prefecture they resolve to
+//! (e.g. 名東県 to Tokushima). Prefectures split between today's prefectures, such as
+//! 熊谷県 (Saitama and Gunma) or 足柄県 (Kanagawa and Shizuoka), and names that were
+//! replaced within a year are not included.
+//!
+//! # Examples
+//!
+//! ```
+//! use jp_prefecture::historical::{self, HistoricalName};
+//! use jp_prefecture::prefectures::Prefecture;
+//!
+//! assert_eq!(historical::find("東京府"), Ok(Prefecture::Tokyo));
+//! assert_eq!(historical::find("東京都"), Ok(Prefecture::Tokyo));
+//!
+//! let name = historical::find_by_name("琉球藩").unwrap();
+//!
+//! assert_eq!(name, HistoricalName::RyukyuHan);
+//! assert_eq!(name.prefecture(), Prefecture::Okinawa);
+//! assert_eq!(name.years(), 1872..=1879);
+//! ```
+
+use std::ops::RangeInclusive;
+
+use crate::mapping::{HistoricalNameData, HISTORICAL_NAME_TABLE};
+use crate::prefectures::{self, Prefecture};
+use crate::Error;
+
+/// A value of historical prefecture name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum HistoricalName {
+    /// 札幌県, one of the three prefectures of Hokkaido
+    Sapporo,
+    /// 函館県, one of the three prefectures of Hokkaido
+    Hakodate,
+    /// 根室県, one of the three prefectures of Hokkaido
+    Nemuro,
+    /// 印旛県, merged with Kisarazu into Chiba
+    Inba,
+    /// 木更津県, merged with Inba into Chiba
+    Kisarazu,
+    /// 東京府, before the Tokyo Metropolis was formed
+    TokyoFu,
+    /// 新川県, merged into Ishikawa before Toyama was split off
+    Niikawa,
+    /// 足羽県, merged into Tsuruga
+    Asuwa,
+    /// 敦賀県, divided between Ishikawa and Shiga before Fukui was formed
+    Tsuruga,
+    /// 筑摩県, divided between Nagano and Gifu
+    Chikuma,
+    /// 浜松県, merged into Shizuoka
+    Hamamatsu,
+    /// 度会県, merged into Mie
+    Watarai,
+    /// 堺県, merged into Osaka
+    Sakai,
+    /// 飾磨県, merged into Hyogo
+    Shikama,
+    /// 名東県, divided between Kochi and Hyogo before Tokushima was formed
+    Myodo,
+    /// 小倉県, merged into Fukuoka
+    Kokura,
+    /// 三潴県, divided between Fukuoka and Nagasaki
+    Mizuma,
+    /// 白川県, the name of Kumamoto from 1872 to 1876
+    Shirakawa,
+    /// 琉球, the Ryukyu Kingdom
+    Ryukyu,
+    /// 琉球藩, the Ryukyu Domain
+    RyukyuHan,
+}
+
+impl HistoricalName {
+    /// Number of historical names
+    pub const COUNT: usize = 20;
+
+    /// All historical names, ordered by the prefecture they became
+    pub const VARIANTS: [HistoricalName; HistoricalName::COUNT] = [
+        HistoricalName::Sapporo,
+        HistoricalName::Hakodate,
+        HistoricalName::Nemuro,
+        HistoricalName::Inba,
+        HistoricalName::Kisarazu,
+        HistoricalName::TokyoFu,
+        HistoricalName::Niikawa,
+        HistoricalName::Asuwa,
+        HistoricalName::Tsuruga,
+        HistoricalName::Chikuma,
+        HistoricalName::Hamamatsu,
+        HistoricalName::Watarai,
+        HistoricalName::Sakai,
+        HistoricalName::Shikama,
+        HistoricalName::Myodo,
+        HistoricalName::Kokura,
+        HistoricalName::Mizuma,
+        HistoricalName::Shirakawa,
+        HistoricalName::Ryukyu,
+        HistoricalName::RyukyuHan,
+    ];
+
+    /// Return the prefecture a historical name corresponds to today
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::historical::HistoricalName;
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(HistoricalName::Nemuro.prefecture(), Prefecture::Hokkaido);
+    /// ```
+    pub fn prefecture(&self) -> Prefecture {
+        self.data().prefecture
+    }
+
+    /// Return the years a historical name was in use, both ends inclusive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::historical::HistoricalName;
+    ///
+    /// assert_eq!(HistoricalName::TokyoFu.years(), 1868..=1943);
+    /// ```
+    pub fn years(&self) -> RangeInclusive<u16> {
+        let data = self.data();
+        data.from..=data.until
+    }
+
+    /// Return a historical name in kanji
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::historical::HistoricalName;
+    ///
+    /// assert_eq!(HistoricalName::Hakodate.kanji(), "函館県".to_string());
+    /// ```
+    pub fn kanji(&self) -> String {
+        self.data().kanji.to_string()
+    }
+
+    /// Return a historical name in hiragana
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::historical::HistoricalName;
+    ///
+    /// assert_eq!(HistoricalName::Hakodate.hiragana(), "はこだてけん".to_string());
+    /// ```
+    #[cfg(feature = "kana")]
+    pub fn hiragana(&self) -> String {
+        self.data().hiragana.to_string()
+    }
+
+    fn data(&self) -> &'static HistoricalNameData {
+        HISTORICAL_NAME_TABLE
+            .iter()
+            .find(|data| data.name == *self)
+            .expect("Unexpected error")
+    }
+}
+
+/// Find a historical name by its kanji or hiragana
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{historical::{self, HistoricalName}, Error};
+///
+/// assert_eq!(historical::find_by_name("札幌県"), Ok(HistoricalName::Sapporo));
+/// # #[cfg(feature = "kana")]
+/// assert_eq!(historical::find_by_name("とうきょうふ"), Ok(HistoricalName::TokyoFu));
+/// assert_eq!(historical::find_by_name("東京都"), Err(Error::InvalidPrefectureName { name: "東京都".to_string() }));
+/// ```
+pub fn find_by_name<T: AsRef<str> + ToString>(name: T) -> Result<HistoricalName, Error> {
+    HISTORICAL_NAME_TABLE
+        .iter()
+        .find(|data| {
+            #[cfg(feature = "kana")]
+            if data.hiragana == name.as_ref() {
+                return true;
+            }
+            data.kanji == name.as_ref()
+        })
+        .map(|data| data.name)
+        .ok_or_else(|| Error::InvalidPrefectureName {
+            name: name.to_string(),
+        })
+}
+
+/// Find a prefecture by its current or historical name
+///
+/// Current names are accepted in the same forms as [`prefectures::find`].
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{historical, prefectures::Prefecture, Error};
+///
+/// assert_eq!(historical::find("琉球"), Ok(Prefecture::Okinawa));
+/// assert_eq!(historical::find("沖縄県"), Ok(Prefecture::Okinawa));
+/// assert_eq!(historical::find("none"), Err(Error::InvalidPrefectureName { name: "none".to_string() }));
+/// ```
+pub fn find<T: AsRef<str>>(s: T) -> Result<Prefecture, Error> {
+    prefectures::find(s.as_ref())
+        .or_else(|_| find_by_name(s.as_ref()).map(|name| name.prefecture()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("札幌県" => Ok(Prefecture::Hokkaido))]
+    #[test_case("函館県" => Ok(Prefecture::Hokkaido))]
+    #[test_case("根室県" => Ok(Prefecture::Hokkaido))]
+    #[test_case("東京府" => Ok(Prefecture::Tokyo))]
+    #[test_case("琉球" => Ok(Prefecture::Okinawa))]
+    #[test_case("琉球藩" => Ok(Prefecture::Okinawa))]
+    #[test_case("東京都" => Ok(Prefecture::Tokyo))]
+    #[test_case("東京県" => Err(Error::InvalidPrefectureName { name: "東京県".to_string() }))]
+    #[test_case("" => Err(Error::InvalidPrefectureName { name: "".to_string() }))]
+    fn find_tests(s: &str) -> Result<Prefecture, Error> {
+        find(s)
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test_case("とうきょうふ" => Ok(Prefecture::Tokyo))]
+    #[test_case("tokyo" => Ok(Prefecture::Tokyo))]
+    fn find_tests_from_kana_and_english(s: &str) -> Result<Prefecture, Error> {
+        find(s)
+    }
+
+    #[test_case(HistoricalName::Sapporo => 1882..=1886)]
+    #[test_case(HistoricalName::TokyoFu => 1868..=1943)]
+    #[test_case(HistoricalName::Inba => 1871..=1873)]
+    #[test_case(HistoricalName::Asuwa => 1871..=1873)]
+    #[test_case(HistoricalName::Chikuma => 1871..=1876)]
+    #[test_case(HistoricalName::Sakai => 1868..=1881)]
+    #[test_case(HistoricalName::Myodo => 1871..=1876)]
+    #[test_case(HistoricalName::Shirakawa => 1872..=1876)]
+    #[test_case(HistoricalName::Ryukyu => 1429..=1879)]
+    #[test_case(HistoricalName::RyukyuHan => 1872..=1879)]
+    fn years_tests(name: HistoricalName) -> RangeInclusive<u16> {
+        name.years()
+    }
+
+    #[test_case("堺県" => Ok(Prefecture::Osaka))]
+    #[test_case("名東県" => Ok(Prefecture::Tokushima))]
+    #[test_case("筑摩県" => Ok(Prefecture::Nagano))]
+    #[test_case("足羽県" => Ok(Prefecture::Fukui))]
+    #[test_case("敦賀県" => Ok(Prefecture::Fukui))]
+    #[test_case("印旛県" => Ok(Prefecture::Chiba))]
+    #[test_case("白川県" => Ok(Prefecture::Kumamoto))]
+    #[test_case("熊谷県" => Err(Error::InvalidPrefectureName { name: "熊谷県".to_string() }) ; "split between prefectures")]
+    fn find_tests_from_meiji_era(s: &str) -> Result<Prefecture, Error> {
+        find(s)
+    }
+
+    #[test]
+    fn table_tests() {
+        for name in HistoricalName::VARIANTS {
+            let years = name.years();
+            assert!(years.start() < years.end(), "{:?}", name);
+            assert!(*years.end() <= 1943, "{:?}", name);
+            assert!(prefectures::find(name.kanji()).is_err(), "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn variants_tests() {
+        for (name, data) in HistoricalName::VARIANTS
+            .iter()
+            .zip(HISTORICAL_NAME_TABLE.iter())
+        {
+            assert_eq!(*name, data.name);
+            assert_eq!(find_by_name(data.kanji), Ok(*name));
+        }
+    }
+}
@@ -0,0 +1,345 @@
+//! Hokkaido subprefectures (総合振興局・振興局)
+//!
+//! # Examples
+//!
+//! ```
+//! use jp_prefecture::hokkaido::{self, Subprefecture};
+//! use jp_prefecture::prefectures::Prefecture;
+//!
+//! let tokachi = Subprefecture::Tokachi;
+//!
+//! assert_eq!(tokachi.kanji(), "十勝総合振興局");
+//! assert_eq!(tokachi.kanji_short(), "十勝");
+//! # #[cfg(feature = "kana")]
+//! assert_eq!(tokachi.hiragana(), "とかちそうごうしんこうきょく");
+//! # #[cfg(feature = "kana")]
+//! assert_eq!(tokachi.katakana(), "トカチソウゴウシンコウキョク");
+//! # #[cfg(feature = "english")]
+//! assert_eq!(tokachi.english(), "Tokachi");
+//!
+//! assert_eq!(hokkaido::find_by_name("十勝"), Ok(Subprefecture::Tokachi));
+//! assert_eq!(Prefecture::Hokkaido.subprefectures().len(), 14);
+//! assert!(Prefecture::Aomori.subprefectures().is_empty());
+//! ```
+
+use crate::mapping::{SubprefectureData, SUBPREFECTURE_TABLE};
+use crate::prefectures::Prefecture;
+use crate::Error;
+
+/// A value of Hokkaido subprefecture
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum Subprefecture {
+    Sorachi,
+    Ishikari,
+    Shiribeshi,
+    Iburi,
+    Hidaka,
+    Oshima,
+    Hiyama,
+    Kamikawa,
+    Rumoi,
+    Soya,
+    Okhotsk,
+    Tokachi,
+    Kushiro,
+    Nemuro,
+}
+
+impl Subprefecture {
+    /// Number of subprefectures
+    pub const COUNT: usize = 14;
+
+    /// All subprefectures, in the order defined by the Hokkaido government
+    pub const VARIANTS: [Subprefecture; Subprefecture::COUNT] = [
+        Subprefecture::Sorachi,
+        Subprefecture::Ishikari,
+        Subprefecture::Shiribeshi,
+        Subprefecture::Iburi,
+        Subprefecture::Hidaka,
+        Subprefecture::Oshima,
+        Subprefecture::Hiyama,
+        Subprefecture::Kamikawa,
+        Subprefecture::Rumoi,
+        Subprefecture::Soya,
+        Subprefecture::Okhotsk,
+        Subprefecture::Tokachi,
+        Subprefecture::Kushiro,
+        Subprefecture::Nemuro,
+    ];
+
+    /// Return whether the subprefecture is a general subprefectural bureau (総合振興局)
+    /// rather than a subprefectural bureau (振興局)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::hokkaido::Subprefecture;
+    ///
+    /// assert!(Subprefecture::Ishikari.is_general());
+    /// assert!(!Subprefecture::Hidaka.is_general());
+    /// ```
+    pub fn is_general(&self) -> bool {
+        self.data().general
+    }
+
+    /// Return a subprefecture name in kanji
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::hokkaido::Subprefecture;
+    ///
+    /// assert_eq!(Subprefecture::Hidaka.kanji(), "日高振興局".to_string());
+    /// ```
+    pub fn kanji(&self) -> String {
+        self.data().kanji.to_string() + self.suffixes().0
+    }
+
+    /// Return a short subprefecture name in kanji
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::hokkaido::Subprefecture;
+    ///
+    /// assert_eq!(Subprefecture::Hidaka.kanji_short(), "日高".to_string());
+    /// ```
+    pub fn kanji_short(&self) -> String {
+        self.data().kanji.to_string()
+    }
+
+    /// Return a subprefecture name in hiragana
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::hokkaido::Subprefecture;
+    ///
+    /// assert_eq!(Subprefecture::Hidaka.hiragana(), "ひだかしんこうきょく".to_string());
+    /// ```
+    #[cfg(feature = "kana")]
+    pub fn hiragana(&self) -> String {
+        self.data().hiragana.to_string() + self.suffixes().1
+    }
+
+    /// Return a subprefecture name in katakana
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::hokkaido::Subprefecture;
+    ///
+    /// assert_eq!(Subprefecture::Hidaka.katakana(), "ヒダカシンコウキョク".to_string());
+    /// ```
+    #[cfg(feature = "kana")]
+    pub fn katakana(&self) -> String {
+        self.data().katakana.to_string() + self.suffixes().2
+    }
+
+    /// Return a subprefecture name in english
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::hokkaido::Subprefecture;
+    ///
+    /// assert_eq!(Subprefecture::Hidaka.english(), "Hidaka".to_string());
+    /// ```
+    #[cfg(feature = "english")]
+    pub fn english(&self) -> String {
+        self.data().english.to_string()
+    }
+
+    /// Suffixes appended to the kanji, hiragana and katakana short names to form the names
+    fn suffixes(&self) -> (&'static str, &'static str, &'static str) {
+        if self.data().general {
+            (
+                "総合振興局",
+                "そうごうしんこうきょく",
+                "ソウゴウシンコウキョク",
+            )
+        } else {
+            ("振興局", "しんこうきょく", "シンコウキョク")
+        }
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        let data = self.data();
+        let (kanji_suffix, hiragana_suffix, katakana_suffix) = self.suffixes();
+        let is_name = |short: &str, suffix: &str| {
+            name.strip_prefix(short)
+                .is_some_and(|rest| rest.is_empty() || rest == suffix)
+        };
+        if is_name(data.kanji, kanji_suffix) {
+            return true;
+        }
+        #[cfg(feature = "kana")]
+        if is_name(data.hiragana, hiragana_suffix) || is_name(data.katakana, katakana_suffix) {
+            return true;
+        }
+        #[cfg(not(feature = "kana"))]
+        let _ = (hiragana_suffix, katakana_suffix);
+        #[cfg(feature = "english")]
+        if data.english.eq_ignore_ascii_case(name) {
+            return true;
+        }
+        false
+    }
+
+    fn data(&self) -> &'static SubprefectureData {
+        SUBPREFECTURE_TABLE
+            .iter()
+            .find(|data| data.subprefecture == *self)
+            .expect("Unexpected error")
+    }
+}
+
+impl Prefecture {
+    /// Return the subprefectures of a prefecture
+    ///
+    /// Only Hokkaido is divided into subprefectures, so this is empty for any other prefecture.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::hokkaido::Subprefecture;
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(Prefecture::Hokkaido.subprefectures()[0], Subprefecture::Sorachi);
+    /// assert!(Prefecture::Tokyo.subprefectures().is_empty());
+    /// ```
+    pub fn subprefectures(&self) -> &'static [Subprefecture] {
+        match self {
+            Prefecture::Hokkaido => &Subprefecture::VARIANTS,
+            _ => &[],
+        }
+    }
+}
+
+/// Find a subprefecture by its name in kanji, hiragana, katakana or english
+///
+/// Names are accepted with or without the 総合振興局/振興局 suffix, and english names are
+/// matched case-insensitively.
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{hokkaido::{self, Subprefecture}, Error};
+///
+/// assert_eq!(hokkaido::find_by_name("釧路総合振興局"), Ok(Subprefecture::Kushiro));
+/// assert_eq!(hokkaido::find_by_name("釧路"), Ok(Subprefecture::Kushiro));
+/// assert_eq!(hokkaido::find_by_name("釧路振興局"), Err(Error::InvalidPrefectureName { name: "釧路振興局".to_string() }));
+/// ```
+pub fn find_by_name<T: AsRef<str> + ToString>(name: T) -> Result<Subprefecture, Error> {
+    Subprefecture::VARIANTS
+        .into_iter()
+        .find(|subprefecture| subprefecture.matches(name.as_ref()))
+        .ok_or_else(|| Error::InvalidPrefectureName {
+            name: name.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Subprefecture::Sorachi => String::from("空知総合振興局"))]
+    #[test_case(Subprefecture::Ishikari => String::from("石狩総合振興局"))]
+    #[test_case(Subprefecture::Shiribeshi => String::from("後志総合振興局"))]
+    #[test_case(Subprefecture::Iburi => String::from("胆振総合振興局"))]
+    #[test_case(Subprefecture::Hidaka => String::from("日高振興局"))]
+    #[test_case(Subprefecture::Oshima => String::from("渡島総合振興局"))]
+    #[test_case(Subprefecture::Hiyama => String::from("檜山振興局"))]
+    #[test_case(Subprefecture::Kamikawa => String::from("上川総合振興局"))]
+    #[test_case(Subprefecture::Rumoi => String::from("留萌振興局"))]
+    #[test_case(Subprefecture::Soya => String::from("宗谷振興局"))]
+    #[test_case(Subprefecture::Okhotsk => String::from("オホーツク総合振興局"))]
+    #[test_case(Subprefecture::Tokachi => String::from("十勝総合振興局"))]
+    #[test_case(Subprefecture::Kushiro => String::from("釧路総合振興局"))]
+    #[test_case(Subprefecture::Nemuro => String::from("根室振興局"))]
+    fn kanji_tests(subprefecture: Subprefecture) -> String {
+        subprefecture.kanji()
+    }
+
+    #[cfg(feature = "kana")]
+    #[test_case(Subprefecture::Sorachi => String::from("そらちそうごうしんこうきょく"))]
+    #[test_case(Subprefecture::Ishikari => String::from("いしかりそうごうしんこうきょく"))]
+    #[test_case(Subprefecture::Shiribeshi => String::from("しりべしそうごうしんこうきょく"))]
+    #[test_case(Subprefecture::Iburi => String::from("いぶりそうごうしんこうきょく"))]
+    #[test_case(Subprefecture::Hidaka => String::from("ひだかしんこうきょく"))]
+    #[test_case(Subprefecture::Oshima => String::from("おしまそうごうしんこうきょく"))]
+    #[test_case(Subprefecture::Hiyama => String::from("ひやましんこうきょく"))]
+    #[test_case(Subprefecture::Kamikawa => String::from("かみかわそうごうしんこうきょく"))]
+    #[test_case(Subprefecture::Rumoi => String::from("るもいしんこうきょく"))]
+    #[test_case(Subprefecture::Soya => String::from("そうやしんこうきょく"))]
+    #[test_case(Subprefecture::Okhotsk => String::from("おほーつくそうごうしんこうきょく"))]
+    #[test_case(Subprefecture::Tokachi => String::from("とかちそうごうしんこうきょく"))]
+    #[test_case(Subprefecture::Kushiro => String::from("くしろそうごうしんこうきょく"))]
+    #[test_case(Subprefecture::Nemuro => String::from("ねむろしんこうきょく"))]
+    fn hiragana_tests(subprefecture: Subprefecture) -> String {
+        subprefecture.hiragana()
+    }
+
+    #[cfg(feature = "kana")]
+    #[test_case(Subprefecture::Sorachi => String::from("ソラチソウゴウシンコウキョク"))]
+    #[test_case(Subprefecture::Ishikari => String::from("イシカリソウゴウシンコウキョク"))]
+    #[test_case(Subprefecture::Shiribeshi => String::from("シリベシソウゴウシンコウキョク"))]
+    #[test_case(Subprefecture::Iburi => String::from("イブリソウゴウシンコウキョク"))]
+    #[test_case(Subprefecture::Hidaka => String::from("ヒダカシンコウキョク"))]
+    #[test_case(Subprefecture::Oshima => String::from("オシマソウゴウシンコウキョク"))]
+    #[test_case(Subprefecture::Hiyama => String::from("ヒヤマシンコウキョク"))]
+    #[test_case(Subprefecture::Kamikawa => String::from("カミカワソウゴウシンコウキョク"))]
+    #[test_case(Subprefecture::Rumoi => String::from("ルモイシンコウキョク"))]
+    #[test_case(Subprefecture::Soya => String::from("ソウヤシンコウキョク"))]
+    #[test_case(Subprefecture::Okhotsk => String::from("オホーツクソウゴウシンコウキョク"))]
+    #[test_case(Subprefecture::Tokachi => String::from("トカチソウゴウシンコウキョク"))]
+    #[test_case(Subprefecture::Kushiro => String::from("クシロソウゴウシンコウキョク"))]
+    #[test_case(Subprefecture::Nemuro => String::from("ネムロシンコウキョク"))]
+    fn katakana_tests(subprefecture: Subprefecture) -> String {
+        subprefecture.katakana()
+    }
+
+    #[test_case("十勝総合振興局" => Ok(Subprefecture::Tokachi))]
+    #[test_case("十勝" => Ok(Subprefecture::Tokachi))]
+    #[test_case("日高振興局" => Ok(Subprefecture::Hidaka))]
+    #[test_case("オホーツク" => Ok(Subprefecture::Okhotsk))]
+    #[test_case("日高総合振興局" => Err(Error::InvalidPrefectureName { name: "日高総合振興局".to_string() }))]
+    #[test_case("北海道" => Err(Error::InvalidPrefectureName { name: "北海道".to_string() }))]
+    #[test_case("" => Err(Error::InvalidPrefectureName { name: "".to_string() }))]
+    fn find_by_name_tests(name: &str) -> Result<Subprefecture, Error> {
+        find_by_name(name)
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test_case("とかちそうごうしんこうきょく" => Ok(Subprefecture::Tokachi))]
+    #[test_case("とかち" => Ok(Subprefecture::Tokachi))]
+    #[test_case("ヒダカシンコウキョク" => Ok(Subprefecture::Hidaka))]
+    #[test_case("ヒダカ" => Ok(Subprefecture::Hidaka))]
+    #[test_case("tokachi" => Ok(Subprefecture::Tokachi))]
+    #[test_case("OKHOTSK" => Ok(Subprefecture::Okhotsk))]
+    #[test_case("tokachi-sogo" => Err(Error::InvalidPrefectureName { name: "tokachi-sogo".to_string() }))]
+    fn find_by_name_tests_from_kana_and_english(name: &str) -> Result<Subprefecture, Error> {
+        find_by_name(name)
+    }
+
+    #[test]
+    fn find_by_name_round_trip_tests() {
+        for subprefecture in Subprefecture::VARIANTS {
+            assert_eq!(find_by_name(subprefecture.kanji()), Ok(subprefecture));
+            assert_eq!(find_by_name(subprefecture.kanji_short()), Ok(subprefecture));
+        }
+    }
+
+    #[test]
+    fn general_count_tests() {
+        let general = Subprefecture::VARIANTS
+            .iter()
+            .filter(|subprefecture| subprefecture.is_general())
+            .count();
+        assert_eq!(general, 9);
+        assert_eq!(SUBPREFECTURE_TABLE.len(), Subprefecture::COUNT);
+    }
+}
@@ -0,0 +1,68 @@
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::mem::size_of;
+
+use crate::mapping::NAME_MAP;
+
+/// Initialization state of a lazily built lookup table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TableReport {
+    /// Name of the table
+    pub name: &'static str,
+    /// Whether the table has been built
+    pub initialized: bool,
+    /// Number of entries, or `0` if the table has not been built yet
+    pub entries: usize,
+    /// Approximate heap memory held by the table in bytes, or `0` if it has not been built yet
+    pub approx_bytes: usize,
+}
+
+/// Report the initialization state of every lazily built lookup table
+///
+/// Checking the state does not build any table.
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{init_report, warm_up};
+///
+/// warm_up();
+/// assert!(init_report().iter().all(|table| table.initialized));
+/// ```
+pub fn init_report() -> Vec<TableReport> {
+    vec![TableReport::new("NAME_MAP", Lazy::get(&NAME_MAP), |name| {
+        name.capacity()
+    })]
+}
+
+/// Build every lazily built lookup table up front
+///
+/// Calling this during start-up keeps the first lookup from paying the build cost.
+pub fn warm_up() {
+    Lazy::force(&NAME_MAP);
+}
+
+impl TableReport {
+    fn new<K, V>(
+        name: &'static str,
+        map: Option<&HashMap<K, V>>,
+        key_heap_bytes: impl Fn(&K) -> usize,
+    ) -> Self {
+        match map {
+            Some(map) => Self {
+                name,
+                initialized: true,
+                entries: map.len(),
+                approx_bytes: map.capacity() * (size_of::<K>() + size_of::<V>())
+                    + map.keys().map(key_heap_bytes).sum::<usize>(),
+            },
+            None => Self {
+                name,
+                initialized: false,
+                entries: 0,
+                approx_bytes: 0,
+            },
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Japan Meteorological Agency forecast office codes
+//!
+//! These are the area codes of the `offices` level in the JMA forecast API
+//! (e.g. `https://www.jma.go.jp/bosai/forecast/data/forecast/130000.json`).
+//! Most prefectures have a single office, while Hokkaido, Kagoshima and Okinawa are
+//! split into several.
+//!
+//! # Examples
+//!
+//! ```
+//! use jp_prefecture::{jma, prefectures::Prefecture};
+//!
+//! assert_eq!(Prefecture::Tokyo.jma_office_code(), "130000");
+//! assert_eq!(Prefecture::Okinawa.jma_office_codes().len(), 4);
+//! assert_eq!(jma::find_by_office_code("474000"), Ok(Prefecture::Okinawa));
+//! ```
+
+use crate::mapping::JMA_OFFICE_TABLE;
+use crate::prefectures::Prefecture;
+use crate::Error;
+
+impl Prefecture {
+    /// Return the JMA forecast office code covering the prefectural capital
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(Prefecture::Tokyo.jma_office_code(), "130000");
+    /// assert_eq!(Prefecture::Hokkaido.jma_office_code(), "016000");
+    /// ```
+    pub fn jma_office_code(&self) -> &'static str {
+        self.jma_office_codes()[0]
+    }
+
+    /// Return all JMA forecast office codes covering the prefecture
+    ///
+    /// The office covering the prefectural capital comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(Prefecture::Kagoshima.jma_office_codes(), &["460100", "460040"]);
+    /// ```
+    pub fn jma_office_codes(&self) -> &'static [&'static str] {
+        JMA_OFFICE_TABLE[self.jis_x_0401_code() as usize - 1]
+    }
+}
+
+/// Find a prefecture by JMA forecast office code
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{jma, prefectures::Prefecture, Error};
+///
+/// assert_eq!(jma::find_by_office_code("130000"), Ok(Prefecture::Tokyo));
+/// assert_eq!(jma::find_by_office_code("014100"), Ok(Prefecture::Hokkaido));
+/// assert_eq!(jma::find_by_office_code("010000"), Err(Error::InvalidJmaOfficeCode { code: "010000".to_string() }));
+/// ```
+pub fn find_by_office_code<T: AsRef<str> + ToString>(code: T) -> Result<Prefecture, Error> {
+    Prefecture::VARIANTS
+        .iter()
+        .find(|pref| pref.jma_office_codes().contains(&code.as_ref()))
+        .copied()
+        .ok_or_else(|| Error::InvalidJmaOfficeCode {
+            code: code.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Prefecture::Hokkaido => "016000")]
+    #[test_case(Prefecture::Aomori => "020000")]
+    #[test_case(Prefecture::Iwate => "030000")]
+    #[test_case(Prefecture::Miyagi => "040000")]
+    #[test_case(Prefecture::Akita => "050000")]
+    #[test_case(Prefecture::Yamagata => "060000")]
+    #[test_case(Prefecture::Fukushima => "070000")]
+    #[test_case(Prefecture::Ibaraki => "080000")]
+    #[test_case(Prefecture::Tochigi => "090000")]
+    #[test_case(Prefecture::Gunma => "100000")]
+    #[test_case(Prefecture::Saitama => "110000")]
+    #[test_case(Prefecture::Chiba => "120000")]
+    #[test_case(Prefecture::Tokyo => "130000")]
+    #[test_case(Prefecture::Kanagawa => "140000")]
+    #[test_case(Prefecture::Niigata => "150000")]
+    #[test_case(Prefecture::Toyama => "160000")]
+    #[test_case(Prefecture::Ishikawa => "170000")]
+    #[test_case(Prefecture::Fukui => "180000")]
+    #[test_case(Prefecture::Yamanashi => "190000")]
+    #[test_case(Prefecture::Nagano => "200000")]
+    #[test_case(Prefecture::Gifu => "210000")]
+    #[test_case(Prefecture::Shizuoka => "220000")]
+    #[test_case(Prefecture::Aichi => "230000")]
+    #[test_case(Prefecture::Mie => "240000")]
+    #[test_case(Prefecture::Shiga => "250000")]
+    #[test_case(Prefecture::Kyoto => "260000")]
+    #[test_case(Prefecture::Osaka => "270000")]
+    #[test_case(Prefecture::Hyogo => "280000")]
+    #[test_case(Prefecture::Nara => "290000")]
+    #[test_case(Prefecture::Wakayama => "300000")]
+    #[test_case(Prefecture::Tottori => "310000")]
+    #[test_case(Prefecture::Shimane => "320000")]
+    #[test_case(Prefecture::Okayama => "330000")]
+    #[test_case(Prefecture::Hiroshima => "340000")]
+    #[test_case(Prefecture::Yamaguchi => "350000")]
+    #[test_case(Prefecture::Tokushima => "360000")]
+    #[test_case(Prefecture::Kagawa => "370000")]
+    #[test_case(Prefecture::Ehime => "380000")]
+    #[test_case(Prefecture::Kochi => "390000")]
+    #[test_case(Prefecture::Fukuoka => "400000")]
+    #[test_case(Prefecture::Saga => "410000")]
+    #[test_case(Prefecture::Nagasaki => "420000")]
+    #[test_case(Prefecture::Kumamoto => "430000")]
+    #[test_case(Prefecture::Oita => "440000")]
+    #[test_case(Prefecture::Miyazaki => "450000")]
+    #[test_case(Prefecture::Kagoshima => "460100")]
+    #[test_case(Prefecture::Okinawa => "471000")]
+    fn jma_office_code_tests(prefecture: Prefecture) -> &'static str {
+        prefecture.jma_office_code()
+    }
+
+    #[test_case("011000" => Ok(Prefecture::Hokkaido))]
+    #[test_case("016000" => Ok(Prefecture::Hokkaido))]
+    #[test_case("020000" => Ok(Prefecture::Aomori))]
+    #[test_case("130000" => Ok(Prefecture::Tokyo))]
+    #[test_case("460040" => Ok(Prefecture::Kagoshima))]
+    #[test_case("460100" => Ok(Prefecture::Kagoshima))]
+    #[test_case("472000" => Ok(Prefecture::Okinawa))]
+    #[test_case("460000" => Err(Error::InvalidJmaOfficeCode { code: "460000".to_string() }))]
+    #[test_case("13" => Err(Error::InvalidJmaOfficeCode { code: "13".to_string() }))]
+    fn find_by_office_code_tests(code: &str) -> Result<Prefecture, Error> {
+        find_by_office_code(code)
+    }
+
+    #[test]
+    fn round_trip_tests() {
+        Prefecture::VARIANTS.iter().for_each(|pref| {
+            pref.jma_office_codes()
+                .iter()
+                .for_each(|code| assert_eq!(find_by_office_code(code), Ok(*pref)));
+        });
+    }
+}
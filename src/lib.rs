@@ -20,22 +20,108 @@
 //! println!("{:?}", tokyo); // => Ok(Prefecture::Tokyo)
 //! println!("{:?}", tokyo.as_ref().unwrap().kanji()); // => "東京都"
 //! println!("{:?}", tokyo.as_ref().unwrap().kanji_short()); // => "東京"
+//! # #[cfg(feature = "english")]
 //! println!("{:?}", tokyo.as_ref().unwrap().english()); // => "Tokyo"
 //!
 //! let tokyo = prefectures::find_by_kanji("東京県"); // uhmmmm...
-//! println!("{:?}", tokyo); // => Err(Error::InvalidPrefectureName("東京県"))
+//! println!("{:?}", tokyo); // => Err(Error::InvalidPrefectureName { name: "東京県" })
 //! ```
+//!
+//! # Safety
+//! This crate contains no `unsafe` code. It is enforced at compile time with
+//! `#![forbid(unsafe_code)]`, so it cannot be opted out of by a nested `allow`.
+
+#![forbid(unsafe_code)]
 
+pub mod designated_cities;
+pub mod finder;
+pub mod historical;
+pub mod hokkaido;
+mod init;
+pub mod jma;
+mod macros;
 mod mapping;
+pub mod metro_areas;
+pub mod pr_blocks;
+pub mod prefecture_map;
 pub mod prefectures;
+pub mod regions;
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::Literal;
+}
+
+pub use init::{init_report, warm_up, TableReport};
 
 /// Enum representing errors related to Japanese prefectures
+///
+/// New variants may be added as finders gain new modes, so matches on it need a
+/// wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
-    /// The prefecture code cannot be parsed or is invalid
-    #[error("Invalid prefecture code: {0}")]
-    InvalidPrefectureCode(u32),
+    /// The prefecture code is a number outside `min..=max`
+    #[error("Prefecture code out of range: {code} (expected {min} to {max})")]
+    CodeOutOfRange { code: u64, min: u64, max: u64 },
+    /// The prefecture code is not a decimal number
+    #[error("Malformed prefecture code: {input}")]
+    MalformedPrefectureCode { input: String },
+    /// The prefecture name cannot be parsed or is invalid
+    #[error("Invalid prefecture name: {name}")]
+    InvalidPrefectureName { name: String },
+    /// The name is valid in a script the finder does not accept
+    #[error("Unsupported script for prefecture name: {name} ({script:?})")]
+    UnsupportedScript {
+        name: String,
+        script: finder::Script,
+    },
+    /// The name matches more than one prefecture
+    #[error("Ambiguous prefecture name: {name} (matches {candidates:?})")]
+    Ambiguous {
+        name: String,
+        candidates: Vec<prefectures::Prefecture>,
+    },
+    /// The ISO 3166-2 code is malformed or does not belong to a prefecture
+    #[error("Invalid ISO 3166-2 code: {code}")]
+    InvalidIsoCode { code: String },
+    /// The JMA forecast office code is invalid
+    #[error("Invalid JMA office code: {code}")]
+    InvalidJmaOfficeCode { code: String },
+    /// The input is longer than the parser accepts
+    #[error("Input too long: {len} bytes (max {max} bytes)")]
+    InputTooLong { len: usize, max: usize },
+}
+
+impl Error {
+    pub(crate) fn code_out_of_range(code: u64) -> Error {
+        Error::CodeOutOfRange {
+            code,
+            min: 1,
+            max: prefectures::Prefecture::COUNT as u64,
+        }
+    }
+}
+
+/// Error borrowing the input that failed to parse
+///
+/// Returned by [`prefectures::parse`], which does not allocate on failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ParseError<'a> {
     /// The prefecture name cannot be parsed or is invalid
-    #[error("Invalid prefecture name: {0}")]
-    InvalidPrefectureName(String),
+    #[error("Invalid prefecture name: {name}")]
+    InvalidPrefectureName { name: &'a str },
+}
+
+impl From<ParseError<'_>> for Error {
+    fn from(err: ParseError<'_>) -> Error {
+        match err {
+            ParseError::InvalidPrefectureName { name } => Error::InvalidPrefectureName {
+                name: name.to_string(),
+            },
+        }
+    }
 }
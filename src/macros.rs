@@ -0,0 +1,159 @@
+use crate::mapping::{PrefectureData, PREFECTURE_TABLE};
+use crate::prefectures::Prefecture;
+
+/// Resolve a prefecture at compile time
+///
+/// Accepts a name in any form accepted by [`prefectures::find`](crate::prefectures::find),
+/// or a JIS X 0401 code. An invalid name or code fails the build.
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{pref, prefectures::Prefecture};
+///
+/// const OSAKA: Prefecture = pref!("大阪");
+///
+/// assert_eq!(pref!("東京都"), Prefecture::Tokyo);
+/// # #[cfg(feature = "english")]
+/// assert_eq!(pref!("Kyoto"), Prefecture::Kyoto);
+/// assert_eq!(pref!(13), Prefecture::Tokyo);
+/// assert_eq!(OSAKA, Prefecture::Osaka);
+/// ```
+///
+/// ```compile_fail
+/// let tokyo = jp_prefecture::pref!("東京県");
+/// ```
+///
+/// ```compile_fail
+/// let unknown = jp_prefecture::pref!(48);
+/// ```
+#[macro_export]
+macro_rules! pref {
+    ($value:literal) => {{
+        const PREFECTURE: $crate::prefectures::Prefecture =
+            $crate::__private::Literal($value).resolve();
+        PREFECTURE
+    }};
+}
+
+/// Literal passed to `pref!`, resolved by a `const fn` for its type
+pub struct Literal<T>(pub T);
+
+impl Literal<&str> {
+    pub const fn resolve(self) -> Prefecture {
+        let name = self.0.as_bytes();
+        let mut i = 0;
+        while i < PREFECTURE_TABLE.len() {
+            if matches_name(name, &PREFECTURE_TABLE[i]) {
+                return PREFECTURE_TABLE[i].prefecture;
+            }
+            i += 1;
+        }
+        panic!("invalid prefecture name");
+    }
+}
+
+impl Literal<u32> {
+    pub const fn resolve(self) -> Prefecture {
+        if self.0 == 0 || self.0 as usize > PREFECTURE_TABLE.len() {
+            panic!("invalid prefecture code");
+        }
+        PREFECTURE_TABLE[self.0 as usize - 1].prefecture
+    }
+}
+
+const fn matches_name(name: &[u8], data: &PrefectureData) -> bool {
+    let (kanji_suffix, hiragana_suffix, katakana_suffix) = data.prefecture.suffixes();
+    if matches_with_suffix(name, data.kanji.as_bytes(), kanji_suffix.as_bytes()) {
+        return true;
+    }
+    #[cfg(feature = "kana")]
+    if matches_with_suffix(name, data.hiragana.as_bytes(), hiragana_suffix.as_bytes())
+        || matches_with_suffix(name, data.katakana.as_bytes(), katakana_suffix.as_bytes())
+    {
+        return true;
+    }
+    #[cfg(not(feature = "kana"))]
+    let _ = (hiragana_suffix, katakana_suffix);
+    #[cfg(feature = "english")]
+    if eq_ignore_ascii_case(name, data.english.as_bytes()) {
+        return true;
+    }
+    false
+}
+
+/// Whether `name` is either `full` or `full` without `suffix`
+const fn matches_with_suffix(name: &[u8], full: &[u8], suffix: &[u8]) -> bool {
+    if name.len() == full.len() {
+        return eq_at(name, full, 0);
+    }
+    name.len() + suffix.len() == full.len()
+        && eq_at(name, full, 0)
+        && eq_at(suffix, full, name.len())
+}
+
+/// Whether `a` equals the bytes of `b` starting at `offset`
+const fn eq_at(a: &[u8], b: &[u8], offset: usize) -> bool {
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[offset + i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(feature = "english")]
+const fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn resolve_name_tests() {
+        PREFECTURE_TABLE.iter().for_each(|data| {
+            for name in data.prefecture.names().forms() {
+                [name, &name.to_uppercase(), &name.to_lowercase()]
+                    .iter()
+                    .for_each(|name| assert_eq!(Literal(*name).resolve(), data.prefecture));
+            }
+        });
+    }
+
+    #[test_case(1 => Prefecture::Hokkaido)]
+    #[test_case(13 => Prefecture::Tokyo)]
+    #[test_case(47 => Prefecture::Okinawa)]
+    fn resolve_code_tests(code: u32) -> Prefecture {
+        Literal(code).resolve()
+    }
+
+    #[test_case(0 ; "zero")]
+    #[test_case(48 ; "out of range")]
+    #[should_panic(expected = "invalid prefecture code")]
+    fn resolve_invalid_code_tests(code: u32) {
+        Literal(code).resolve();
+    }
+
+    #[test_case("東京県")]
+    #[test_case("都")]
+    #[test_case("")]
+    #[should_panic(expected = "invalid prefecture name")]
+    fn resolve_invalid_name_tests(name: &str) {
+        Literal(name).resolve();
+    }
+}
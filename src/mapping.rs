@@ -1,220 +1,1577 @@
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 
+use crate::designated_cities::DesignatedCity;
+use crate::historical::HistoricalName;
+use crate::hokkaido::Subprefecture;
+use crate::metro_areas::MetroArea;
+use crate::pr_blocks::PrBlock;
 use crate::prefectures::Prefecture;
+use crate::regions::Region;
 
-pub(crate) struct PrefectureData {
+/// Record of a prefecture as stored in the crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PrefectureData {
+    /// The prefecture
+    pub prefecture: Prefecture,
+    /// Name in kanji (e.g. "東京都")
     pub kanji: &'static str,
+    /// Name in hiragana (e.g. "とうきょうと")
+    #[cfg(feature = "kana")]
     pub hiragana: &'static str,
+    /// Name in katakana (e.g. "トウキョウト")
+    #[cfg(feature = "kana")]
     pub katakana: &'static str,
+    /// Name in english (e.g. "Tokyo")
+    #[cfg(feature = "english")]
     pub english: &'static str,
+    /// Name in Kunrei-shiki romanization (e.g. "Tôkyô")
+    #[cfg(feature = "english")]
+    pub kunrei: &'static str,
 }
 
 impl PrefectureData {
-    fn new(
+    const fn new(
+        prefecture: Prefecture,
         kanji: &'static str,
         hiragana: &'static str,
         katakana: &'static str,
         english: &'static str,
+        kunrei: &'static str,
     ) -> Self {
+        #[cfg(not(feature = "kana"))]
+        let _ = (hiragana, katakana);
+        #[cfg(not(feature = "english"))]
+        let _ = (english, kunrei);
         Self {
+            prefecture,
             kanji,
+            #[cfg(feature = "kana")]
             hiragana,
+            #[cfg(feature = "kana")]
             katakana,
+            #[cfg(feature = "english")]
             english,
+            #[cfg(feature = "english")]
+            kunrei,
         }
     }
 }
 
-pub(crate) static PREFECTURE_MAP: Lazy<HashMap<Prefecture, PrefectureData>> = Lazy::new(|| {
-    let mut map = HashMap::new();
-    map.insert(
+/// Data of every prefecture, ordered by JIS X 0401 code
+pub(crate) const PREFECTURE_TABLE: [PrefectureData; Prefecture::COUNT] = [
+    PrefectureData::new(
         Prefecture::Hokkaido,
-        PrefectureData::new("北海道", "ほっかいどう", "ホッカイドウ", "hokkaido"),
-    );
-    map.insert(
+        "北海道",
+        "ほっかいどう",
+        "ホッカイドウ",
+        "Hokkaido",
+        "Hokkaidô",
+    ),
+    PrefectureData::new(
         Prefecture::Aomori,
-        PrefectureData::new("青森県", "あおもりけん", "アオモリケン", "aomori"),
-    );
-    map.insert(
+        "青森県",
+        "あおもりけん",
+        "アオモリケン",
+        "Aomori",
+        "Aomori",
+    ),
+    PrefectureData::new(
         Prefecture::Iwate,
-        PrefectureData::new("岩手県", "いわてけん", "イワテケン", "iwate"),
-    );
-    map.insert(
+        "岩手県",
+        "いわてけん",
+        "イワテケン",
+        "Iwate",
+        "Iwate",
+    ),
+    PrefectureData::new(
         Prefecture::Miyagi,
-        PrefectureData::new("宮城県", "みやぎけん", "ミヤギケン", "miyagi"),
-    );
-    map.insert(
+        "宮城県",
+        "みやぎけん",
+        "ミヤギケン",
+        "Miyagi",
+        "Miyagi",
+    ),
+    PrefectureData::new(
         Prefecture::Akita,
-        PrefectureData::new("秋田県", "あきたけん", "アキタケン", "akita"),
-    );
-    map.insert(
+        "秋田県",
+        "あきたけん",
+        "アキタケン",
+        "Akita",
+        "Akita",
+    ),
+    PrefectureData::new(
         Prefecture::Yamagata,
-        PrefectureData::new("山形県", "やまがたけん", "ヤマガタケン", "yamagata"),
-    );
-    map.insert(
+        "山形県",
+        "やまがたけん",
+        "ヤマガタケン",
+        "Yamagata",
+        "Yamagata",
+    ),
+    PrefectureData::new(
         Prefecture::Fukushima,
-        PrefectureData::new("福島県", "ふくしまけん", "フクシマケン", "fukushima"),
-    );
-    map.insert(
+        "福島県",
+        "ふくしまけん",
+        "フクシマケン",
+        "Fukushima",
+        "Hukusima",
+    ),
+    PrefectureData::new(
         Prefecture::Ibaraki,
-        PrefectureData::new("茨城県", "いばらきけん", "イバラキケン", "ibaraki"),
-    );
-    map.insert(
+        "茨城県",
+        "いばらきけん",
+        "イバラキケン",
+        "Ibaraki",
+        "Ibaraki",
+    ),
+    PrefectureData::new(
         Prefecture::Tochigi,
-        PrefectureData::new("栃木県", "とちぎけん", "トチギケン", "tochigi"),
-    );
-    map.insert(
+        "栃木県",
+        "とちぎけん",
+        "トチギケン",
+        "Tochigi",
+        "Totigi",
+    ),
+    PrefectureData::new(
         Prefecture::Gunma,
-        PrefectureData::new("群馬県", "ぐんまけん", "グンマケン", "gunma"),
-    );
-    map.insert(
+        "群馬県",
+        "ぐんまけん",
+        "グンマケン",
+        "Gunma",
+        "Gunma",
+    ),
+    PrefectureData::new(
         Prefecture::Saitama,
-        PrefectureData::new("埼玉県", "さいたまけん", "サイタマケン", "saitama"),
-    );
-    map.insert(
+        "埼玉県",
+        "さいたまけん",
+        "サイタマケン",
+        "Saitama",
+        "Saitama",
+    ),
+    PrefectureData::new(
         Prefecture::Chiba,
-        PrefectureData::new("千葉県", "ちばけん", "チバケン", "chiba"),
-    );
-    map.insert(
+        "千葉県",
+        "ちばけん",
+        "チバケン",
+        "Chiba",
+        "Tiba",
+    ),
+    PrefectureData::new(
         Prefecture::Tokyo,
-        PrefectureData::new("東京都", "とうきょうと", "トウキョウト", "tokyo"),
-    );
-    map.insert(
+        "東京都",
+        "とうきょうと",
+        "トウキョウト",
+        "Tokyo",
+        "Tôkyô",
+    ),
+    PrefectureData::new(
         Prefecture::Kanagawa,
-        PrefectureData::new("神奈川県", "かながわけん", "カナガワケン", "kanagawa"),
-    );
-    map.insert(
+        "神奈川県",
+        "かながわけん",
+        "カナガワケン",
+        "Kanagawa",
+        "Kanagawa",
+    ),
+    PrefectureData::new(
         Prefecture::Niigata,
-        PrefectureData::new("新潟県", "にいがたけん", "ニイガタケン", "niigata"),
-    );
-    map.insert(
+        "新潟県",
+        "にいがたけん",
+        "ニイガタケン",
+        "Niigata",
+        "Niigata",
+    ),
+    PrefectureData::new(
         Prefecture::Toyama,
-        PrefectureData::new("富山県", "とやまけん", "トヤマケン", "toyama"),
-    );
-    map.insert(
+        "富山県",
+        "とやまけん",
+        "トヤマケン",
+        "Toyama",
+        "Toyama",
+    ),
+    PrefectureData::new(
         Prefecture::Ishikawa,
-        PrefectureData::new("石川県", "いしかわけん", "イシカワケン", "ishikawa"),
-    );
-    map.insert(
+        "石川県",
+        "いしかわけん",
+        "イシカワケン",
+        "Ishikawa",
+        "Isikawa",
+    ),
+    PrefectureData::new(
         Prefecture::Fukui,
-        PrefectureData::new("福井県", "ふくいけん", "フクイケン", "fukui"),
-    );
-    map.insert(
+        "福井県",
+        "ふくいけん",
+        "フクイケン",
+        "Fukui",
+        "Hukui",
+    ),
+    PrefectureData::new(
         Prefecture::Yamanashi,
-        PrefectureData::new("山梨県", "やまなしけん", "ヤマナシケン", "yamanashi"),
-    );
-    map.insert(
+        "山梨県",
+        "やまなしけん",
+        "ヤマナシケン",
+        "Yamanashi",
+        "Yamanasi",
+    ),
+    PrefectureData::new(
         Prefecture::Nagano,
-        PrefectureData::new("長野県", "ながのけん", "ナガノケン", "nagano"),
-    );
-    map.insert(
+        "長野県",
+        "ながのけん",
+        "ナガノケン",
+        "Nagano",
+        "Nagano",
+    ),
+    PrefectureData::new(
         Prefecture::Gifu,
-        PrefectureData::new("岐阜県", "ぎふけん", "ギフケン", "gifu"),
-    );
-    map.insert(
+        "岐阜県",
+        "ぎふけん",
+        "ギフケン",
+        "Gifu",
+        "Gihu",
+    ),
+    PrefectureData::new(
         Prefecture::Shizuoka,
-        PrefectureData::new("静岡県", "しずおかけん", "シズオカケン", "shizuoka"),
-    );
-    map.insert(
+        "静岡県",
+        "しずおかけん",
+        "シズオカケン",
+        "Shizuoka",
+        "Sizuoka",
+    ),
+    PrefectureData::new(
         Prefecture::Aichi,
-        PrefectureData::new("愛知県", "あいちけん", "アイチケン", "aichi"),
-    );
-    map.insert(
+        "愛知県",
+        "あいちけん",
+        "アイチケン",
+        "Aichi",
+        "Aiti",
+    ),
+    PrefectureData::new(
         Prefecture::Mie,
-        PrefectureData::new("三重県", "みえけん", "ミエケン", "mie"),
-    );
-    map.insert(
+        "三重県",
+        "みえけん",
+        "ミエケン",
+        "Mie",
+        "Mie",
+    ),
+    PrefectureData::new(
         Prefecture::Shiga,
-        PrefectureData::new("滋賀県", "しがけん", "シガケン", "shiga"),
-    );
-    map.insert(
+        "滋賀県",
+        "しがけん",
+        "シガケン",
+        "Shiga",
+        "Siga",
+    ),
+    PrefectureData::new(
         Prefecture::Kyoto,
-        PrefectureData::new("京都府", "きょうとふ", "キョウトフ", "kyoto"),
-    );
-    map.insert(
+        "京都府",
+        "きょうとふ",
+        "キョウトフ",
+        "Kyoto",
+        "Kyôto",
+    ),
+    PrefectureData::new(
         Prefecture::Osaka,
-        PrefectureData::new("大阪府", "おおさかふ", "オオサカフ", "osaka"),
-    );
-    map.insert(
+        "大阪府",
+        "おおさかふ",
+        "オオサカフ",
+        "Osaka",
+        "Ôsaka",
+    ),
+    PrefectureData::new(
         Prefecture::Hyogo,
-        PrefectureData::new("兵庫県", "ひょうごけん", "ヒョウゴケン", "hyogo"),
-    );
-    map.insert(
+        "兵庫県",
+        "ひょうごけん",
+        "ヒョウゴケン",
+        "Hyogo",
+        "Hyôgo",
+    ),
+    PrefectureData::new(
         Prefecture::Nara,
-        PrefectureData::new("奈良県", "ならけん", "ナラケン", "nara"),
-    );
-    map.insert(
+        "奈良県",
+        "ならけん",
+        "ナラケン",
+        "Nara",
+        "Nara",
+    ),
+    PrefectureData::new(
         Prefecture::Wakayama,
-        PrefectureData::new("和歌山県", "わかやまけん", "ワカヤマケン", "wakayama"),
-    );
-    map.insert(
+        "和歌山県",
+        "わかやまけん",
+        "ワカヤマケン",
+        "Wakayama",
+        "Wakayama",
+    ),
+    PrefectureData::new(
         Prefecture::Tottori,
-        PrefectureData::new("鳥取県", "とっとりけん", "トットリケン", "tottori"),
-    );
-    map.insert(
+        "鳥取県",
+        "とっとりけん",
+        "トットリケン",
+        "Tottori",
+        "Tottori",
+    ),
+    PrefectureData::new(
         Prefecture::Shimane,
-        PrefectureData::new("島根県", "しまねけん", "シマネケン", "shimane"),
-    );
-    map.insert(
+        "島根県",
+        "しまねけん",
+        "シマネケン",
+        "Shimane",
+        "Simane",
+    ),
+    PrefectureData::new(
         Prefecture::Okayama,
-        PrefectureData::new("岡山県", "おかやまけん", "オカヤマケン", "okayama"),
-    );
-    map.insert(
+        "岡山県",
+        "おかやまけん",
+        "オカヤマケン",
+        "Okayama",
+        "Okayama",
+    ),
+    PrefectureData::new(
         Prefecture::Hiroshima,
-        PrefectureData::new("広島県", "ひろしまけん", "ヒロシマケン", "hiroshima"),
-    );
-    map.insert(
+        "広島県",
+        "ひろしまけん",
+        "ヒロシマケン",
+        "Hiroshima",
+        "Hirosima",
+    ),
+    PrefectureData::new(
         Prefecture::Yamaguchi,
-        PrefectureData::new("山口県", "やまぐちけん", "ヤマグチケン", "yamaguchi"),
-    );
-    map.insert(
+        "山口県",
+        "やまぐちけん",
+        "ヤマグチケン",
+        "Yamaguchi",
+        "Yamaguti",
+    ),
+    PrefectureData::new(
         Prefecture::Tokushima,
-        PrefectureData::new("徳島県", "とくしまけん", "トクシマケン", "tokushima"),
-    );
-    map.insert(
+        "徳島県",
+        "とくしまけん",
+        "トクシマケン",
+        "Tokushima",
+        "Tokusima",
+    ),
+    PrefectureData::new(
         Prefecture::Kagawa,
-        PrefectureData::new("香川県", "かがわけん", "カガワケン", "kagawa"),
-    );
-    map.insert(
+        "香川県",
+        "かがわけん",
+        "カガワケン",
+        "Kagawa",
+        "Kagawa",
+    ),
+    PrefectureData::new(
         Prefecture::Ehime,
-        PrefectureData::new("愛媛県", "えひめけん", "エヒメケン", "ehime"),
-    );
-    map.insert(
+        "愛媛県",
+        "えひめけん",
+        "エヒメケン",
+        "Ehime",
+        "Ehime",
+    ),
+    PrefectureData::new(
         Prefecture::Kochi,
-        PrefectureData::new("高知県", "こうちけん", "コウチケン", "kochi"),
-    );
-    map.insert(
+        "高知県",
+        "こうちけん",
+        "コウチケン",
+        "Kochi",
+        "Kôti",
+    ),
+    PrefectureData::new(
         Prefecture::Fukuoka,
-        PrefectureData::new("福岡県", "ふくおかけん", "フクオカケン", "fukuoka"),
-    );
-    map.insert(
+        "福岡県",
+        "ふくおかけん",
+        "フクオカケン",
+        "Fukuoka",
+        "Hukuoka",
+    ),
+    PrefectureData::new(
         Prefecture::Saga,
-        PrefectureData::new("佐賀県", "さがけん", "サガケン", "saga"),
-    );
-    map.insert(
+        "佐賀県",
+        "さがけん",
+        "サガケン",
+        "Saga",
+        "Saga",
+    ),
+    PrefectureData::new(
         Prefecture::Nagasaki,
-        PrefectureData::new("長崎県", "ながさきけん", "ナガサキケン", "nagasaki"),
-    );
-    map.insert(
+        "長崎県",
+        "ながさきけん",
+        "ナガサキケン",
+        "Nagasaki",
+        "Nagasaki",
+    ),
+    PrefectureData::new(
         Prefecture::Kumamoto,
-        PrefectureData::new("熊本県", "くまもとけん", "クマモトケン", "kumamoto"),
-    );
-    map.insert(
+        "熊本県",
+        "くまもとけん",
+        "クマモトケン",
+        "Kumamoto",
+        "Kumamoto",
+    ),
+    PrefectureData::new(
         Prefecture::Oita,
-        PrefectureData::new("大分県", "おおいたけん", "オオイタケン", "oita"),
-    );
-    map.insert(
+        "大分県",
+        "おおいたけん",
+        "オオイタケン",
+        "Oita",
+        "Ôita",
+    ),
+    PrefectureData::new(
         Prefecture::Miyazaki,
-        PrefectureData::new("宮崎県", "みやざきけん", "ミヤザキケン", "miyazaki"),
-    );
-    map.insert(
+        "宮崎県",
+        "みやざきけん",
+        "ミヤザキケン",
+        "Miyazaki",
+        "Miyazaki",
+    ),
+    PrefectureData::new(
         Prefecture::Kagoshima,
-        PrefectureData::new("鹿児島県", "かごしまけん", "カゴシマケン", "kagoshima"),
-    );
-    map.insert(
+        "鹿児島県",
+        "かごしまけん",
+        "カゴシマケン",
+        "Kagoshima",
+        "Kagosima",
+    ),
+    PrefectureData::new(
         Prefecture::Okinawa,
-        PrefectureData::new("沖縄県", "おきなわけん", "オキナワケン", "okinawa"),
-    );
+        "沖縄県",
+        "おきなわけん",
+        "オキナワケン",
+        "Okinawa",
+        "Okinawa",
+    ),
+];
+
+#[cfg(feature = "i18n")]
+pub(crate) struct LocalizedData {
+    pub zh_hans: &'static str,
+    pub zh_hant: &'static str,
+    pub ko: &'static str,
+}
+
+#[cfg(feature = "i18n")]
+impl LocalizedData {
+    const fn new(zh_hans: &'static str, zh_hant: &'static str, ko: &'static str) -> Self {
+        Self {
+            zh_hans,
+            zh_hant,
+            ko,
+        }
+    }
+}
+
+/// Chinese and Korean names of every prefecture, ordered by JIS X 0401 code
+#[cfg(feature = "i18n")]
+pub(crate) static LOCALIZED_TABLE: [LocalizedData; Prefecture::COUNT] = [
+    LocalizedData::new("北海道", "北海道", "홋카이도"),
+    LocalizedData::new("青森县", "青森縣", "아오모리현"),
+    LocalizedData::new("岩手县", "岩手縣", "이와테현"),
+    LocalizedData::new("宫城县", "宮城縣", "미야기현"),
+    LocalizedData::new("秋田县", "秋田縣", "아키타현"),
+    LocalizedData::new("山形县", "山形縣", "야마가타현"),
+    LocalizedData::new("福岛县", "福島縣", "후쿠시마현"),
+    LocalizedData::new("茨城县", "茨城縣", "이바라키현"),
+    LocalizedData::new("栃木县", "栃木縣", "도치기현"),
+    LocalizedData::new("群马县", "群馬縣", "군마현"),
+    LocalizedData::new("埼玉县", "埼玉縣", "사이타마현"),
+    LocalizedData::new("千叶县", "千葉縣", "지바현"),
+    LocalizedData::new("东京都", "東京都", "도쿄도"),
+    LocalizedData::new("神奈川县", "神奈川縣", "가나가와현"),
+    LocalizedData::new("新潟县", "新潟縣", "니가타현"),
+    LocalizedData::new("富山县", "富山縣", "도야마현"),
+    LocalizedData::new("石川县", "石川縣", "이시카와현"),
+    LocalizedData::new("福井县", "福井縣", "후쿠이현"),
+    LocalizedData::new("山梨县", "山梨縣", "야마나시현"),
+    LocalizedData::new("长野县", "長野縣", "나가노현"),
+    LocalizedData::new("岐阜县", "岐阜縣", "기후현"),
+    LocalizedData::new("静冈县", "靜岡縣", "시즈오카현"),
+    LocalizedData::new("爱知县", "愛知縣", "아이치현"),
+    LocalizedData::new("三重县", "三重縣", "미에현"),
+    LocalizedData::new("滋贺县", "滋賀縣", "시가현"),
+    LocalizedData::new("京都府", "京都府", "교토부"),
+    LocalizedData::new("大阪府", "大阪府", "오사카부"),
+    LocalizedData::new("兵库县", "兵庫縣", "효고현"),
+    LocalizedData::new("奈良县", "奈良縣", "나라현"),
+    LocalizedData::new("和歌山县", "和歌山縣", "와카야마현"),
+    LocalizedData::new("鸟取县", "鳥取縣", "돗토리현"),
+    LocalizedData::new("岛根县", "島根縣", "시마네현"),
+    LocalizedData::new("冈山县", "岡山縣", "오카야마현"),
+    LocalizedData::new("广岛县", "廣島縣", "히로시마현"),
+    LocalizedData::new("山口县", "山口縣", "야마구치현"),
+    LocalizedData::new("德岛县", "德島縣", "도쿠시마현"),
+    LocalizedData::new("香川县", "香川縣", "가가와현"),
+    LocalizedData::new("爱媛县", "愛媛縣", "에히메현"),
+    LocalizedData::new("高知县", "高知縣", "고치현"),
+    LocalizedData::new("福冈县", "福岡縣", "후쿠오카현"),
+    LocalizedData::new("佐贺县", "佐賀縣", "사가현"),
+    LocalizedData::new("长崎县", "長崎縣", "나가사키현"),
+    LocalizedData::new("熊本县", "熊本縣", "구마모토현"),
+    LocalizedData::new("大分县", "大分縣", "오이타현"),
+    LocalizedData::new("宫崎县", "宮崎縣", "미야자키현"),
+    LocalizedData::new("鹿儿岛县", "鹿兒島縣", "가고시마현"),
+    LocalizedData::new("冲绳县", "沖繩縣", "오키나와현"),
+];
+
+pub(crate) struct DesignatedCityData {
+    pub city: DesignatedCity,
+    pub prefecture: Prefecture,
+    pub kanji: &'static str,
+    #[cfg(feature = "kana")]
+    pub hiragana: &'static str,
+    #[cfg(feature = "kana")]
+    pub katakana: &'static str,
+    #[cfg(feature = "english")]
+    pub english: &'static str,
+}
+
+impl DesignatedCityData {
+    const fn new(
+        city: DesignatedCity,
+        prefecture: Prefecture,
+        kanji: &'static str,
+        hiragana: &'static str,
+        katakana: &'static str,
+        english: &'static str,
+    ) -> Self {
+        #[cfg(not(feature = "kana"))]
+        let _ = (hiragana, katakana);
+        #[cfg(not(feature = "english"))]
+        let _ = english;
+        Self {
+            city,
+            prefecture,
+            kanji,
+            #[cfg(feature = "kana")]
+            hiragana,
+            #[cfg(feature = "kana")]
+            katakana,
+            #[cfg(feature = "english")]
+            english,
+        }
+    }
+}
+
+/// Data of every ordinance-designated city, ordered by local government code
+pub(crate) static DESIGNATED_CITY_TABLE: [DesignatedCityData; DesignatedCity::COUNT] = [
+    DesignatedCityData::new(
+        DesignatedCity::Sapporo,
+        Prefecture::Hokkaido,
+        "札幌市",
+        "さっぽろし",
+        "サッポロシ",
+        "Sapporo",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Sendai,
+        Prefecture::Miyagi,
+        "仙台市",
+        "せんだいし",
+        "センダイシ",
+        "Sendai",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Saitama,
+        Prefecture::Saitama,
+        "さいたま市",
+        "さいたまし",
+        "サイタマシ",
+        "Saitama",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Chiba,
+        Prefecture::Chiba,
+        "千葉市",
+        "ちばし",
+        "チバシ",
+        "Chiba",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Yokohama,
+        Prefecture::Kanagawa,
+        "横浜市",
+        "よこはまし",
+        "ヨコハマシ",
+        "Yokohama",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Kawasaki,
+        Prefecture::Kanagawa,
+        "川崎市",
+        "かわさきし",
+        "カワサキシ",
+        "Kawasaki",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Sagamihara,
+        Prefecture::Kanagawa,
+        "相模原市",
+        "さがみはらし",
+        "サガミハラシ",
+        "Sagamihara",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Niigata,
+        Prefecture::Niigata,
+        "新潟市",
+        "にいがたし",
+        "ニイガタシ",
+        "Niigata",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Shizuoka,
+        Prefecture::Shizuoka,
+        "静岡市",
+        "しずおかし",
+        "シズオカシ",
+        "Shizuoka",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Hamamatsu,
+        Prefecture::Shizuoka,
+        "浜松市",
+        "はままつし",
+        "ハママツシ",
+        "Hamamatsu",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Nagoya,
+        Prefecture::Aichi,
+        "名古屋市",
+        "なごやし",
+        "ナゴヤシ",
+        "Nagoya",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Kyoto,
+        Prefecture::Kyoto,
+        "京都市",
+        "きょうとし",
+        "キョウトシ",
+        "Kyoto",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Osaka,
+        Prefecture::Osaka,
+        "大阪市",
+        "おおさかし",
+        "オオサカシ",
+        "Osaka",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Sakai,
+        Prefecture::Osaka,
+        "堺市",
+        "さかいし",
+        "サカイシ",
+        "Sakai",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Kobe,
+        Prefecture::Hyogo,
+        "神戸市",
+        "こうべし",
+        "コウベシ",
+        "Kobe",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Okayama,
+        Prefecture::Okayama,
+        "岡山市",
+        "おかやまし",
+        "オカヤマシ",
+        "Okayama",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Hiroshima,
+        Prefecture::Hiroshima,
+        "広島市",
+        "ひろしまし",
+        "ヒロシマシ",
+        "Hiroshima",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Kitakyushu,
+        Prefecture::Fukuoka,
+        "北九州市",
+        "きたきゅうしゅうし",
+        "キタキュウシュウシ",
+        "Kitakyushu",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Fukuoka,
+        Prefecture::Fukuoka,
+        "福岡市",
+        "ふくおかし",
+        "フクオカシ",
+        "Fukuoka",
+    ),
+    DesignatedCityData::new(
+        DesignatedCity::Kumamoto,
+        Prefecture::Kumamoto,
+        "熊本市",
+        "くまもとし",
+        "クマモトシ",
+        "Kumamoto",
+    ),
+];
+
+pub(crate) struct SubprefectureData {
+    pub subprefecture: Subprefecture,
+    pub kanji: &'static str,
+    #[cfg(feature = "kana")]
+    pub hiragana: &'static str,
+    #[cfg(feature = "kana")]
+    pub katakana: &'static str,
+    #[cfg(feature = "english")]
+    pub english: &'static str,
+    pub general: bool,
+}
+
+impl SubprefectureData {
+    const fn new(
+        subprefecture: Subprefecture,
+        kanji: &'static str,
+        hiragana: &'static str,
+        katakana: &'static str,
+        english: &'static str,
+        general: bool,
+    ) -> Self {
+        #[cfg(not(feature = "kana"))]
+        let _ = (hiragana, katakana);
+        #[cfg(not(feature = "english"))]
+        let _ = english;
+        Self {
+            subprefecture,
+            kanji,
+            #[cfg(feature = "kana")]
+            hiragana,
+            #[cfg(feature = "kana")]
+            katakana,
+            #[cfg(feature = "english")]
+            english,
+            general,
+        }
+    }
+}
+
+/// Data of every Hokkaido subprefecture, without the 総合振興局/振興局 suffix
+pub(crate) static SUBPREFECTURE_TABLE: [SubprefectureData; Subprefecture::COUNT] = [
+    SubprefectureData::new(
+        Subprefecture::Sorachi,
+        "空知",
+        "そらち",
+        "ソラチ",
+        "Sorachi",
+        true,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Ishikari,
+        "石狩",
+        "いしかり",
+        "イシカリ",
+        "Ishikari",
+        true,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Shiribeshi,
+        "後志",
+        "しりべし",
+        "シリベシ",
+        "Shiribeshi",
+        true,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Iburi,
+        "胆振",
+        "いぶり",
+        "イブリ",
+        "Iburi",
+        true,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Hidaka,
+        "日高",
+        "ひだか",
+        "ヒダカ",
+        "Hidaka",
+        false,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Oshima,
+        "渡島",
+        "おしま",
+        "オシマ",
+        "Oshima",
+        true,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Hiyama,
+        "檜山",
+        "ひやま",
+        "ヒヤマ",
+        "Hiyama",
+        false,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Kamikawa,
+        "上川",
+        "かみかわ",
+        "カミカワ",
+        "Kamikawa",
+        true,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Rumoi,
+        "留萌",
+        "るもい",
+        "ルモイ",
+        "Rumoi",
+        false,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Soya,
+        "宗谷",
+        "そうや",
+        "ソウヤ",
+        "Soya",
+        false,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Okhotsk,
+        "オホーツク",
+        "おほーつく",
+        "オホーツク",
+        "Okhotsk",
+        true,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Tokachi,
+        "十勝",
+        "とかち",
+        "トカチ",
+        "Tokachi",
+        true,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Kushiro,
+        "釧路",
+        "くしろ",
+        "クシロ",
+        "Kushiro",
+        true,
+    ),
+    SubprefectureData::new(
+        Subprefecture::Nemuro,
+        "根室",
+        "ねむろ",
+        "ネムロ",
+        "Nemuro",
+        false,
+    ),
+];
+
+pub(crate) struct PrBlockData {
+    pub block: PrBlock,
+    pub kanji: &'static str,
+    #[cfg(feature = "kana")]
+    pub hiragana: &'static str,
+    #[cfg(feature = "kana")]
+    pub katakana: &'static str,
+    #[cfg(feature = "english")]
+    pub english: &'static str,
+    pub prefectures: &'static [Prefecture],
+}
+
+impl PrBlockData {
+    const fn new(
+        block: PrBlock,
+        kanji: &'static str,
+        hiragana: &'static str,
+        katakana: &'static str,
+        english: &'static str,
+        prefectures: &'static [Prefecture],
+    ) -> Self {
+        #[cfg(not(feature = "kana"))]
+        let _ = (hiragana, katakana);
+        #[cfg(not(feature = "english"))]
+        let _ = english;
+        Self {
+            block,
+            kanji,
+            #[cfg(feature = "kana")]
+            hiragana,
+            #[cfg(feature = "kana")]
+            katakana,
+            #[cfg(feature = "english")]
+            english,
+            prefectures,
+        }
+    }
+}
+
+/// Data of every proportional representation block, from Hokkaido to Kyushu
+pub(crate) static PR_BLOCK_TABLE: [PrBlockData; PrBlock::COUNT] = PR_BLOCKS;
+
+/// Proportional representation block of every prefecture, ordered by JIS X 0401 code
+pub(crate) static PREFECTURE_PR_BLOCKS: [PrBlock; Prefecture::COUNT] =
+    pr_blocks_by_prefecture(&PR_BLOCKS);
+
+const fn pr_blocks_by_prefecture(table: &[PrBlockData]) -> [PrBlock; Prefecture::COUNT] {
+    let mut blocks = [PrBlock::Hokkaido; Prefecture::COUNT];
+    let mut i = 0;
+    while i < table.len() {
+        let prefectures = table[i].prefectures;
+        let mut j = 0;
+        while j < prefectures.len() {
+            blocks[prefectures[j] as usize - 1] = table[i].block;
+            j += 1;
+        }
+        i += 1;
+    }
+    blocks
+}
+
+const PR_BLOCKS: [PrBlockData; PrBlock::COUNT] = [
+    PrBlockData::new(
+        PrBlock::Hokkaido,
+        "北海道",
+        "ほっかいどう",
+        "ホッカイドウ",
+        "Hokkaido",
+        &[Prefecture::Hokkaido],
+    ),
+    PrBlockData::new(
+        PrBlock::Tohoku,
+        "東北",
+        "とうほく",
+        "トウホク",
+        "Tohoku",
+        &[
+            Prefecture::Aomori,
+            Prefecture::Iwate,
+            Prefecture::Miyagi,
+            Prefecture::Akita,
+            Prefecture::Yamagata,
+            Prefecture::Fukushima,
+        ],
+    ),
+    PrBlockData::new(
+        PrBlock::KitaKanto,
+        "北関東",
+        "きたかんとう",
+        "キタカントウ",
+        "Kita-Kanto",
+        &[
+            Prefecture::Ibaraki,
+            Prefecture::Tochigi,
+            Prefecture::Gunma,
+            Prefecture::Saitama,
+        ],
+    ),
+    PrBlockData::new(
+        PrBlock::MinamiKanto,
+        "南関東",
+        "みなみかんとう",
+        "ミナミカントウ",
+        "Minami-Kanto",
+        &[
+            Prefecture::Chiba,
+            Prefecture::Kanagawa,
+            Prefecture::Yamanashi,
+        ],
+    ),
+    PrBlockData::new(
+        PrBlock::Tokyo,
+        "東京",
+        "とうきょう",
+        "トウキョウ",
+        "Tokyo",
+        &[Prefecture::Tokyo],
+    ),
+    PrBlockData::new(
+        PrBlock::HokurikuShinetsu,
+        "北陸信越",
+        "ほくりくしんえつ",
+        "ホクリクシンエツ",
+        "Hokuriku-Shinetsu",
+        &[
+            Prefecture::Niigata,
+            Prefecture::Toyama,
+            Prefecture::Ishikawa,
+            Prefecture::Fukui,
+            Prefecture::Nagano,
+        ],
+    ),
+    PrBlockData::new(
+        PrBlock::Tokai,
+        "東海",
+        "とうかい",
+        "トウカイ",
+        "Tokai",
+        &[
+            Prefecture::Gifu,
+            Prefecture::Shizuoka,
+            Prefecture::Aichi,
+            Prefecture::Mie,
+        ],
+    ),
+    PrBlockData::new(
+        PrBlock::Kinki,
+        "近畿",
+        "きんき",
+        "キンキ",
+        "Kinki",
+        &[
+            Prefecture::Shiga,
+            Prefecture::Kyoto,
+            Prefecture::Osaka,
+            Prefecture::Hyogo,
+            Prefecture::Nara,
+            Prefecture::Wakayama,
+        ],
+    ),
+    PrBlockData::new(
+        PrBlock::Chugoku,
+        "中国",
+        "ちゅうごく",
+        "チュウゴク",
+        "Chugoku",
+        &[
+            Prefecture::Tottori,
+            Prefecture::Shimane,
+            Prefecture::Okayama,
+            Prefecture::Hiroshima,
+            Prefecture::Yamaguchi,
+        ],
+    ),
+    PrBlockData::new(
+        PrBlock::Shikoku,
+        "四国",
+        "しこく",
+        "シコク",
+        "Shikoku",
+        &[
+            Prefecture::Tokushima,
+            Prefecture::Kagawa,
+            Prefecture::Ehime,
+            Prefecture::Kochi,
+        ],
+    ),
+    PrBlockData::new(
+        PrBlock::Kyushu,
+        "九州",
+        "きゅうしゅう",
+        "キュウシュウ",
+        "Kyushu",
+        &[
+            Prefecture::Fukuoka,
+            Prefecture::Saga,
+            Prefecture::Nagasaki,
+            Prefecture::Kumamoto,
+            Prefecture::Oita,
+            Prefecture::Miyazaki,
+            Prefecture::Kagoshima,
+            Prefecture::Okinawa,
+        ],
+    ),
+];
+
+pub(crate) struct RegionData {
+    pub region: Region,
+    pub kanji: &'static str,
+    #[cfg(feature = "kana")]
+    pub hiragana: &'static str,
+    #[cfg(feature = "kana")]
+    pub katakana: &'static str,
+    #[cfg(feature = "english")]
+    pub english: &'static str,
+    pub prefectures: &'static [Prefecture],
+}
+
+impl RegionData {
+    const fn new(
+        region: Region,
+        kanji: &'static str,
+        hiragana: &'static str,
+        katakana: &'static str,
+        english: &'static str,
+        prefectures: &'static [Prefecture],
+    ) -> Self {
+        #[cfg(not(feature = "kana"))]
+        let _ = (hiragana, katakana);
+        #[cfg(not(feature = "english"))]
+        let _ = english;
+        Self {
+            region,
+            kanji,
+            #[cfg(feature = "kana")]
+            hiragana,
+            #[cfg(feature = "kana")]
+            katakana,
+            #[cfg(feature = "english")]
+            english,
+            prefectures,
+        }
+    }
+}
+
+/// Data of every region, from Hokkaido to Kyushu
+pub(crate) static REGION_TABLE: [RegionData; Region::COUNT] = [
+    RegionData::new(
+        Region::Hokkaido,
+        "北海道",
+        "ほっかいどう",
+        "ホッカイドウ",
+        "Hokkaido",
+        &[Prefecture::Hokkaido],
+    ),
+    RegionData::new(
+        Region::Tohoku,
+        "東北",
+        "とうほく",
+        "トウホク",
+        "Tohoku",
+        &[
+            Prefecture::Aomori,
+            Prefecture::Iwate,
+            Prefecture::Miyagi,
+            Prefecture::Akita,
+            Prefecture::Yamagata,
+            Prefecture::Fukushima,
+        ],
+    ),
+    RegionData::new(
+        Region::Kanto,
+        "関東",
+        "かんとう",
+        "カントウ",
+        "Kanto",
+        &[
+            Prefecture::Ibaraki,
+            Prefecture::Tochigi,
+            Prefecture::Gunma,
+            Prefecture::Saitama,
+            Prefecture::Chiba,
+            Prefecture::Tokyo,
+            Prefecture::Kanagawa,
+        ],
+    ),
+    RegionData::new(
+        Region::Chubu,
+        "中部",
+        "ちゅうぶ",
+        "チュウブ",
+        "Chubu",
+        &[
+            Prefecture::Niigata,
+            Prefecture::Toyama,
+            Prefecture::Ishikawa,
+            Prefecture::Fukui,
+            Prefecture::Yamanashi,
+            Prefecture::Nagano,
+            Prefecture::Gifu,
+            Prefecture::Shizuoka,
+            Prefecture::Aichi,
+        ],
+    ),
+    RegionData::new(
+        Region::Kinki,
+        "近畿",
+        "きんき",
+        "キンキ",
+        "Kinki",
+        &[
+            Prefecture::Mie,
+            Prefecture::Shiga,
+            Prefecture::Kyoto,
+            Prefecture::Osaka,
+            Prefecture::Hyogo,
+            Prefecture::Nara,
+            Prefecture::Wakayama,
+        ],
+    ),
+    RegionData::new(
+        Region::Chugoku,
+        "中国",
+        "ちゅうごく",
+        "チュウゴク",
+        "Chugoku",
+        &[
+            Prefecture::Tottori,
+            Prefecture::Shimane,
+            Prefecture::Okayama,
+            Prefecture::Hiroshima,
+            Prefecture::Yamaguchi,
+        ],
+    ),
+    RegionData::new(
+        Region::Shikoku,
+        "四国",
+        "しこく",
+        "シコク",
+        "Shikoku",
+        &[
+            Prefecture::Tokushima,
+            Prefecture::Kagawa,
+            Prefecture::Ehime,
+            Prefecture::Kochi,
+        ],
+    ),
+    RegionData::new(
+        Region::Kyushu,
+        "九州",
+        "きゅうしゅう",
+        "キュウシュウ",
+        "Kyushu",
+        &[
+            Prefecture::Fukuoka,
+            Prefecture::Saga,
+            Prefecture::Nagasaki,
+            Prefecture::Kumamoto,
+            Prefecture::Oita,
+            Prefecture::Miyazaki,
+            Prefecture::Kagoshima,
+            Prefecture::Okinawa,
+        ],
+    ),
+];
+
+pub(crate) struct MetroAreaData {
+    pub area: MetroArea,
+    pub kanji: &'static str,
+    #[cfg(feature = "kana")]
+    pub hiragana: &'static str,
+    #[cfg(feature = "kana")]
+    pub katakana: &'static str,
+    #[cfg(feature = "english")]
+    pub english: &'static str,
+    pub prefectures: &'static [Prefecture],
+}
+
+impl MetroAreaData {
+    const fn new(
+        area: MetroArea,
+        kanji: &'static str,
+        hiragana: &'static str,
+        katakana: &'static str,
+        english: &'static str,
+        prefectures: &'static [Prefecture],
+    ) -> Self {
+        #[cfg(not(feature = "kana"))]
+        let _ = (hiragana, katakana);
+        #[cfg(not(feature = "english"))]
+        let _ = english;
+        Self {
+            area,
+            kanji,
+            #[cfg(feature = "kana")]
+            hiragana,
+            #[cfg(feature = "kana")]
+            katakana,
+            #[cfg(feature = "english")]
+            english,
+            prefectures,
+        }
+    }
+}
+
+/// Data of every metropolitan area, from east to west
+pub(crate) static METRO_AREA_TABLE: [MetroAreaData; MetroArea::COUNT] = [
+    MetroAreaData::new(
+        MetroArea::GreaterTokyo,
+        "首都圏",
+        "しゅとけん",
+        "シュトケン",
+        "Greater Tokyo",
+        &[
+            Prefecture::Saitama,
+            Prefecture::Chiba,
+            Prefecture::Tokyo,
+            Prefecture::Kanagawa,
+        ],
+    ),
+    MetroAreaData::new(
+        MetroArea::Chukyo,
+        "中京圏",
+        "ちゅうきょうけん",
+        "チュウキョウケン",
+        "Chukyo",
+        &[Prefecture::Gifu, Prefecture::Aichi, Prefecture::Mie],
+    ),
+    MetroAreaData::new(
+        MetroArea::Keihanshin,
+        "京阪神",
+        "けいはんしん",
+        "ケイハンシン",
+        "Keihanshin",
+        &[Prefecture::Kyoto, Prefecture::Osaka, Prefecture::Hyogo],
+    ),
+];
+
+pub(crate) struct HistoricalNameData {
+    pub name: HistoricalName,
+    pub kanji: &'static str,
+    #[cfg(feature = "kana")]
+    pub hiragana: &'static str,
+    pub prefecture: Prefecture,
+    pub from: u16,
+    pub until: u16,
+}
+
+impl HistoricalNameData {
+    const fn new(
+        name: HistoricalName,
+        kanji: &'static str,
+        hiragana: &'static str,
+        prefecture: Prefecture,
+        from: u16,
+        until: u16,
+    ) -> Self {
+        #[cfg(not(feature = "kana"))]
+        let _ = hiragana;
+        Self {
+            name,
+            kanji,
+            #[cfg(feature = "kana")]
+            hiragana,
+            prefecture,
+            from,
+            until,
+        }
+    }
+}
+
+/// Data of every historical name, ordered by the prefecture it became
+pub(crate) static HISTORICAL_NAME_TABLE: [HistoricalNameData; HistoricalName::COUNT] = [
+    HistoricalNameData::new(
+        HistoricalName::Sapporo,
+        "札幌県",
+        "さっぽろけん",
+        Prefecture::Hokkaido,
+        1882,
+        1886,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Hakodate,
+        "函館県",
+        "はこだてけん",
+        Prefecture::Hokkaido,
+        1882,
+        1886,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Nemuro,
+        "根室県",
+        "ねむろけん",
+        Prefecture::Hokkaido,
+        1882,
+        1886,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Inba,
+        "印旛県",
+        "いんばけん",
+        Prefecture::Chiba,
+        1871,
+        1873,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Kisarazu,
+        "木更津県",
+        "きさらづけん",
+        Prefecture::Chiba,
+        1871,
+        1873,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::TokyoFu,
+        "東京府",
+        "とうきょうふ",
+        Prefecture::Tokyo,
+        1868,
+        1943,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Niikawa,
+        "新川県",
+        "にいかわけん",
+        Prefecture::Toyama,
+        1871,
+        1876,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Asuwa,
+        "足羽県",
+        "あすわけん",
+        Prefecture::Fukui,
+        1871,
+        1873,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Tsuruga,
+        "敦賀県",
+        "つるがけん",
+        Prefecture::Fukui,
+        1871,
+        1876,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Chikuma,
+        "筑摩県",
+        "ちくまけん",
+        Prefecture::Nagano,
+        1871,
+        1876,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Hamamatsu,
+        "浜松県",
+        "はままつけん",
+        Prefecture::Shizuoka,
+        1871,
+        1876,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Watarai,
+        "度会県",
+        "わたらいけん",
+        Prefecture::Mie,
+        1871,
+        1876,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Sakai,
+        "堺県",
+        "さかいけん",
+        Prefecture::Osaka,
+        1868,
+        1881,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Shikama,
+        "飾磨県",
+        "しかまけん",
+        Prefecture::Hyogo,
+        1871,
+        1876,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Myodo,
+        "名東県",
+        "みょうどうけん",
+        Prefecture::Tokushima,
+        1871,
+        1876,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Kokura,
+        "小倉県",
+        "こくらけん",
+        Prefecture::Fukuoka,
+        1871,
+        1876,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Mizuma,
+        "三潴県",
+        "みずまけん",
+        Prefecture::Fukuoka,
+        1871,
+        1876,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Shirakawa,
+        "白川県",
+        "しらかわけん",
+        Prefecture::Kumamoto,
+        1872,
+        1876,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::Ryukyu,
+        "琉球",
+        "りゅうきゅう",
+        Prefecture::Okinawa,
+        1429,
+        1879,
+    ),
+    HistoricalNameData::new(
+        HistoricalName::RyukyuHan,
+        "琉球藩",
+        "りゅうきゅうはん",
+        Prefecture::Okinawa,
+        1872,
+        1879,
+    ),
+];
+
+/// JMA forecast office codes of every prefecture, ordered by JIS X 0401 code
+///
+/// The office covering the prefectural capital comes first.
+pub(crate) static JMA_OFFICE_TABLE: [&[&str]; Prefecture::COUNT] = [
+    &[
+        "016000", "011000", "012000", "013000", "014030", "014100", "015000", "017000",
+    ],
+    &["020000"],
+    &["030000"],
+    &["040000"],
+    &["050000"],
+    &["060000"],
+    &["070000"],
+    &["080000"],
+    &["090000"],
+    &["100000"],
+    &["110000"],
+    &["120000"],
+    &["130000"],
+    &["140000"],
+    &["150000"],
+    &["160000"],
+    &["170000"],
+    &["180000"],
+    &["190000"],
+    &["200000"],
+    &["210000"],
+    &["220000"],
+    &["230000"],
+    &["240000"],
+    &["250000"],
+    &["260000"],
+    &["270000"],
+    &["280000"],
+    &["290000"],
+    &["300000"],
+    &["310000"],
+    &["320000"],
+    &["330000"],
+    &["340000"],
+    &["350000"],
+    &["360000"],
+    &["370000"],
+    &["380000"],
+    &["390000"],
+    &["400000"],
+    &["410000"],
+    &["420000"],
+    &["430000"],
+    &["440000"],
+    &["450000"],
+    &["460100", "460040"],
+    &["471000", "472000", "473000", "474000"],
+];
+
+/// Every name form of every prefecture, with english in lower case
+pub(crate) static NAME_MAP: Lazy<HashMap<String, Prefecture>> = Lazy::new(|| {
+    let mut map = HashMap::new();
+    Prefecture::VARIANTS.iter().for_each(|pref| {
+        map.insert(pref.kanji(), *pref);
+        map.insert(pref.kanji_short(), *pref);
+        #[cfg(feature = "kana")]
+        {
+            map.insert(pref.hiragana(), *pref);
+            map.insert(pref.hiragana_short(), *pref);
+            map.insert(pref.katakana(), *pref);
+            map.insert(pref.katakana_short(), *pref);
+        }
+        #[cfg(feature = "english")]
+        map.insert(pref.english().to_lowercase(), *pref);
+    });
     map
 });
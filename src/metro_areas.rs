@@ -0,0 +1,185 @@
+//! the three major metropolitan areas (三大都市圏) by prefecture
+//!
+//! Areas are given in their common prefecture-level definitions. The Greater Tokyo Area
+//! is the "1都3県" of Tokyo, Saitama, Chiba and Kanagawa rather than the wider area of
+//! the National Capital Region Development Act, and prefectures outside the three areas
+//! belong to none.
+//!
+//! # Examples
+//!
+//! ```
+//! use jp_prefecture::metro_areas::MetroArea;
+//! use jp_prefecture::prefectures::Prefecture;
+//!
+//! assert_eq!(Prefecture::Hyogo.metro_area(), Some(MetroArea::Keihanshin));
+//! assert_eq!(Prefecture::Nara.metro_area(), None);
+//! assert_eq!(MetroArea::GreaterTokyo.kanji(), "首都圏");
+//! assert_eq!(
+//!     MetroArea::Chukyo.prefectures(),
+//!     &[Prefecture::Gifu, Prefecture::Aichi, Prefecture::Mie]
+//! );
+//! ```
+
+use crate::mapping::{MetroAreaData, METRO_AREA_TABLE};
+use crate::prefectures::Prefecture;
+
+/// A value of metropolitan area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum MetroArea {
+    GreaterTokyo,
+    Chukyo,
+    Keihanshin,
+}
+
+impl MetroArea {
+    /// Number of metropolitan areas
+    pub const COUNT: usize = 3;
+
+    /// All metropolitan areas, from east to west
+    pub const VARIANTS: [MetroArea; MetroArea::COUNT] = [
+        MetroArea::GreaterTokyo,
+        MetroArea::Chukyo,
+        MetroArea::Keihanshin,
+    ];
+
+    /// Return the prefectures in a metropolitan area, ordered by JIS X 0401 code
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::metro_areas::MetroArea;
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(
+    ///     MetroArea::Keihanshin.prefectures(),
+    ///     &[Prefecture::Kyoto, Prefecture::Osaka, Prefecture::Hyogo]
+    /// );
+    /// ```
+    pub fn prefectures(&self) -> &'static [Prefecture] {
+        self.data().prefectures
+    }
+
+    /// Return a metropolitan area name in kanji
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::metro_areas::MetroArea;
+    ///
+    /// assert_eq!(MetroArea::Chukyo.kanji(), "中京圏".to_string());
+    /// ```
+    pub fn kanji(&self) -> String {
+        self.data().kanji.to_string()
+    }
+
+    /// Return a metropolitan area name in hiragana
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::metro_areas::MetroArea;
+    ///
+    /// assert_eq!(MetroArea::Chukyo.hiragana(), "ちゅうきょうけん".to_string());
+    /// ```
+    #[cfg(feature = "kana")]
+    pub fn hiragana(&self) -> String {
+        self.data().hiragana.to_string()
+    }
+
+    /// Return a metropolitan area name in katakana
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::metro_areas::MetroArea;
+    ///
+    /// assert_eq!(MetroArea::Chukyo.katakana(), "チュウキョウケン".to_string());
+    /// ```
+    #[cfg(feature = "kana")]
+    pub fn katakana(&self) -> String {
+        self.data().katakana.to_string()
+    }
+
+    /// Return a metropolitan area name in english
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::metro_areas::MetroArea;
+    ///
+    /// assert_eq!(MetroArea::GreaterTokyo.english(), "Greater Tokyo".to_string());
+    /// ```
+    #[cfg(feature = "english")]
+    pub fn english(&self) -> String {
+        self.data().english.to_string()
+    }
+
+    fn data(&self) -> &'static MetroAreaData {
+        METRO_AREA_TABLE
+            .iter()
+            .find(|data| data.area == *self)
+            .expect("Unexpected error")
+    }
+}
+
+impl Prefecture {
+    /// Return the metropolitan area a prefecture belongs to, if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::metro_areas::MetroArea;
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(Prefecture::Saitama.metro_area(), Some(MetroArea::GreaterTokyo));
+    /// assert_eq!(Prefecture::Ibaraki.metro_area(), None);
+    /// ```
+    pub fn metro_area(&self) -> Option<MetroArea> {
+        METRO_AREA_TABLE
+            .iter()
+            .find(|data| data.prefectures.contains(self))
+            .map(|data| data.area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Prefecture::Saitama => Some(MetroArea::GreaterTokyo))]
+    #[test_case(Prefecture::Chiba => Some(MetroArea::GreaterTokyo))]
+    #[test_case(Prefecture::Tokyo => Some(MetroArea::GreaterTokyo))]
+    #[test_case(Prefecture::Kanagawa => Some(MetroArea::GreaterTokyo))]
+    #[test_case(Prefecture::Gifu => Some(MetroArea::Chukyo))]
+    #[test_case(Prefecture::Aichi => Some(MetroArea::Chukyo))]
+    #[test_case(Prefecture::Mie => Some(MetroArea::Chukyo))]
+    #[test_case(Prefecture::Kyoto => Some(MetroArea::Keihanshin))]
+    #[test_case(Prefecture::Osaka => Some(MetroArea::Keihanshin))]
+    #[test_case(Prefecture::Hyogo => Some(MetroArea::Keihanshin))]
+    #[test_case(Prefecture::Hokkaido => None)]
+    #[test_case(Prefecture::Ibaraki => None)]
+    #[test_case(Prefecture::Yamanashi => None)]
+    #[test_case(Prefecture::Shizuoka => None)]
+    #[test_case(Prefecture::Nara => None)]
+    #[test_case(Prefecture::Okinawa => None)]
+    fn metro_area_tests(prefecture: Prefecture) -> Option<MetroArea> {
+        prefecture.metro_area()
+    }
+
+    #[test]
+    fn prefectures_tests() {
+        let members: usize = MetroArea::VARIANTS
+            .iter()
+            .map(|area| area.prefectures().len())
+            .sum();
+        let with_area = Prefecture::VARIANTS
+            .iter()
+            .filter(|pref| pref.metro_area().is_some())
+            .count();
+        assert_eq!(members, 10);
+        assert_eq!(with_area, members);
+    }
+}
@@ -0,0 +1,227 @@
+//! proportional representation blocks (比例代表ブロック) of the House of Representatives
+//!
+//! # Examples
+//!
+//! ```
+//! use jp_prefecture::pr_blocks::PrBlock;
+//! use jp_prefecture::prefectures::Prefecture;
+//!
+//! let block = Prefecture::Yamanashi.pr_block();
+//!
+//! assert_eq!(block, PrBlock::MinamiKanto);
+//! assert_eq!(block.kanji(), "南関東");
+//! # #[cfg(feature = "english")]
+//! assert_eq!(block.english(), "Minami-Kanto");
+//! assert_eq!(
+//!     block.prefectures(),
+//!     &[Prefecture::Chiba, Prefecture::Kanagawa, Prefecture::Yamanashi]
+//! );
+//! ```
+
+use crate::mapping::{PrBlockData, PREFECTURE_PR_BLOCKS, PR_BLOCK_TABLE};
+use crate::prefectures::Prefecture;
+
+/// A value of proportional representation block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum PrBlock {
+    Hokkaido,
+    Tohoku,
+    KitaKanto,
+    MinamiKanto,
+    Tokyo,
+    HokurikuShinetsu,
+    Tokai,
+    Kinki,
+    Chugoku,
+    Shikoku,
+    Kyushu,
+}
+
+impl PrBlock {
+    /// Number of blocks
+    pub const COUNT: usize = 11;
+
+    /// All blocks, from Hokkaido to Kyushu
+    pub const VARIANTS: [PrBlock; PrBlock::COUNT] = [
+        PrBlock::Hokkaido,
+        PrBlock::Tohoku,
+        PrBlock::KitaKanto,
+        PrBlock::MinamiKanto,
+        PrBlock::Tokyo,
+        PrBlock::HokurikuShinetsu,
+        PrBlock::Tokai,
+        PrBlock::Kinki,
+        PrBlock::Chugoku,
+        PrBlock::Shikoku,
+        PrBlock::Kyushu,
+    ];
+
+    /// Return the prefectures in a block, ordered by JIS X 0401 code
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::pr_blocks::PrBlock;
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(PrBlock::Tokyo.prefectures(), &[Prefecture::Tokyo]);
+    /// ```
+    pub fn prefectures(&self) -> &'static [Prefecture] {
+        self.data().prefectures
+    }
+
+    /// Return a block name in kanji
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::pr_blocks::PrBlock;
+    ///
+    /// assert_eq!(PrBlock::HokurikuShinetsu.kanji(), "北陸信越".to_string());
+    /// ```
+    pub fn kanji(&self) -> String {
+        self.data().kanji.to_string()
+    }
+
+    /// Return a block name in hiragana
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::pr_blocks::PrBlock;
+    ///
+    /// assert_eq!(PrBlock::HokurikuShinetsu.hiragana(), "ほくりくしんえつ".to_string());
+    /// ```
+    #[cfg(feature = "kana")]
+    pub fn hiragana(&self) -> String {
+        self.data().hiragana.to_string()
+    }
+
+    /// Return a block name in katakana
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::pr_blocks::PrBlock;
+    ///
+    /// assert_eq!(PrBlock::HokurikuShinetsu.katakana(), "ホクリクシンエツ".to_string());
+    /// ```
+    #[cfg(feature = "kana")]
+    pub fn katakana(&self) -> String {
+        self.data().katakana.to_string()
+    }
+
+    /// Return a block name in english
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::pr_blocks::PrBlock;
+    ///
+    /// assert_eq!(PrBlock::HokurikuShinetsu.english(), "Hokuriku-Shinetsu".to_string());
+    /// ```
+    #[cfg(feature = "english")]
+    pub fn english(&self) -> String {
+        self.data().english.to_string()
+    }
+
+    fn data(&self) -> &'static PrBlockData {
+        &PR_BLOCK_TABLE[*self as usize]
+    }
+}
+
+impl Prefecture {
+    /// Return the proportional representation block a prefecture belongs to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::pr_blocks::PrBlock;
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(Prefecture::Niigata.pr_block(), PrBlock::HokurikuShinetsu);
+    /// assert_eq!(Prefecture::Okinawa.pr_block(), PrBlock::Kyushu);
+    /// ```
+    pub fn pr_block(&self) -> PrBlock {
+        PREFECTURE_PR_BLOCKS[*self as usize - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Prefecture::Hokkaido => PrBlock::Hokkaido)]
+    #[test_case(Prefecture::Aomori => PrBlock::Tohoku)]
+    #[test_case(Prefecture::Iwate => PrBlock::Tohoku)]
+    #[test_case(Prefecture::Miyagi => PrBlock::Tohoku)]
+    #[test_case(Prefecture::Akita => PrBlock::Tohoku)]
+    #[test_case(Prefecture::Yamagata => PrBlock::Tohoku)]
+    #[test_case(Prefecture::Fukushima => PrBlock::Tohoku)]
+    #[test_case(Prefecture::Ibaraki => PrBlock::KitaKanto)]
+    #[test_case(Prefecture::Tochigi => PrBlock::KitaKanto)]
+    #[test_case(Prefecture::Gunma => PrBlock::KitaKanto)]
+    #[test_case(Prefecture::Saitama => PrBlock::KitaKanto)]
+    #[test_case(Prefecture::Chiba => PrBlock::MinamiKanto)]
+    #[test_case(Prefecture::Kanagawa => PrBlock::MinamiKanto)]
+    #[test_case(Prefecture::Yamanashi => PrBlock::MinamiKanto)]
+    #[test_case(Prefecture::Tokyo => PrBlock::Tokyo)]
+    #[test_case(Prefecture::Niigata => PrBlock::HokurikuShinetsu)]
+    #[test_case(Prefecture::Toyama => PrBlock::HokurikuShinetsu)]
+    #[test_case(Prefecture::Ishikawa => PrBlock::HokurikuShinetsu)]
+    #[test_case(Prefecture::Fukui => PrBlock::HokurikuShinetsu)]
+    #[test_case(Prefecture::Nagano => PrBlock::HokurikuShinetsu)]
+    #[test_case(Prefecture::Gifu => PrBlock::Tokai)]
+    #[test_case(Prefecture::Shizuoka => PrBlock::Tokai)]
+    #[test_case(Prefecture::Aichi => PrBlock::Tokai)]
+    #[test_case(Prefecture::Mie => PrBlock::Tokai)]
+    #[test_case(Prefecture::Shiga => PrBlock::Kinki)]
+    #[test_case(Prefecture::Kyoto => PrBlock::Kinki)]
+    #[test_case(Prefecture::Osaka => PrBlock::Kinki)]
+    #[test_case(Prefecture::Hyogo => PrBlock::Kinki)]
+    #[test_case(Prefecture::Nara => PrBlock::Kinki)]
+    #[test_case(Prefecture::Wakayama => PrBlock::Kinki)]
+    #[test_case(Prefecture::Tottori => PrBlock::Chugoku)]
+    #[test_case(Prefecture::Shimane => PrBlock::Chugoku)]
+    #[test_case(Prefecture::Okayama => PrBlock::Chugoku)]
+    #[test_case(Prefecture::Hiroshima => PrBlock::Chugoku)]
+    #[test_case(Prefecture::Yamaguchi => PrBlock::Chugoku)]
+    #[test_case(Prefecture::Tokushima => PrBlock::Shikoku)]
+    #[test_case(Prefecture::Kagawa => PrBlock::Shikoku)]
+    #[test_case(Prefecture::Ehime => PrBlock::Shikoku)]
+    #[test_case(Prefecture::Kochi => PrBlock::Shikoku)]
+    #[test_case(Prefecture::Fukuoka => PrBlock::Kyushu)]
+    #[test_case(Prefecture::Saga => PrBlock::Kyushu)]
+    #[test_case(Prefecture::Nagasaki => PrBlock::Kyushu)]
+    #[test_case(Prefecture::Kumamoto => PrBlock::Kyushu)]
+    #[test_case(Prefecture::Oita => PrBlock::Kyushu)]
+    #[test_case(Prefecture::Miyazaki => PrBlock::Kyushu)]
+    #[test_case(Prefecture::Kagoshima => PrBlock::Kyushu)]
+    #[test_case(Prefecture::Okinawa => PrBlock::Kyushu)]
+    fn pr_block_tests(prefecture: Prefecture) -> PrBlock {
+        prefecture.pr_block()
+    }
+
+    #[test]
+    fn prefectures_tests() {
+        let mut prefectures: Vec<Prefecture> = PrBlock::VARIANTS
+            .iter()
+            .flat_map(|block| block.prefectures().iter().copied())
+            .collect();
+        prefectures.sort_by_key(Prefecture::jis_x_0401_code);
+        assert_eq!(prefectures, Prefecture::VARIANTS);
+    }
+
+    #[test]
+    fn variants_tests() {
+        for (block, data) in PrBlock::VARIANTS.iter().zip(PR_BLOCK_TABLE.iter()) {
+            assert_eq!(*block, data.block);
+            for pref in block.prefectures() {
+                assert_eq!(pref.pr_block(), *block);
+            }
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! dense map keyed by prefecture
+//!
+//! [`PrefectureMap`] stores one slot per prefecture in an array indexed by JIS X 0401
+//! code, so lookups do not hash and iteration is always in code order.
+//!
+//! # Examples
+//!
+//! ```
+//! use jp_prefecture::prefecture_map::PrefectureMap;
+//! use jp_prefecture::prefectures::Prefecture;
+//!
+//! let mut counts: PrefectureMap<u32> = PrefectureMap::new();
+//! for pref in [Prefecture::Tokyo, Prefecture::Osaka, Prefecture::Tokyo] {
+//!     *counts.get_or_insert_with(pref, Default::default) += 1;
+//! }
+//!
+//! assert_eq!(counts[Prefecture::Tokyo], 2);
+//! assert_eq!(
+//!     counts.iter().collect::<Vec<_>>(),
+//!     vec![(Prefecture::Tokyo, &2), (Prefecture::Osaka, &1)]
+//! );
+//! ```
+
+use std::ops::{Index, IndexMut};
+
+use crate::prefectures::Prefecture;
+
+/// A map from prefectures to values backed by an array
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrefectureMap<V> {
+    slots: [Option<V>; Prefecture::COUNT],
+}
+
+impl<V> PrefectureMap<V> {
+    /// Return an empty map
+    pub fn new() -> PrefectureMap<V> {
+        PrefectureMap {
+            slots: std::array::from_fn(|_| None),
+        }
+    }
+
+    /// Return the number of prefectures with a value
+    pub fn len(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.is_some()).count()
+    }
+
+    /// Return whether no prefecture has a value
+    pub fn is_empty(&self) -> bool {
+        self.slots.iter().all(Option::is_none)
+    }
+
+    /// Return whether a prefecture has a value
+    pub fn contains_key(&self, prefecture: Prefecture) -> bool {
+        self.slots[index(prefecture)].is_some()
+    }
+
+    /// Return the value of a prefecture
+    pub fn get(&self, prefecture: Prefecture) -> Option<&V> {
+        self.slots[index(prefecture)].as_ref()
+    }
+
+    /// Return the value of a prefecture mutably
+    pub fn get_mut(&mut self, prefecture: Prefecture) -> Option<&mut V> {
+        self.slots[index(prefecture)].as_mut()
+    }
+
+    /// Return the value of a prefecture, inserting one from `f` if there is none
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, prefecture: Prefecture, f: F) -> &mut V {
+        self.slots[index(prefecture)].get_or_insert_with(f)
+    }
+
+    /// Set the value of a prefecture and return the previous one
+    pub fn insert(&mut self, prefecture: Prefecture, value: V) -> Option<V> {
+        self.slots[index(prefecture)].replace(value)
+    }
+
+    /// Remove the value of a prefecture and return it
+    pub fn remove(&mut self, prefecture: Prefecture) -> Option<V> {
+        self.slots[index(prefecture)].take()
+    }
+
+    /// Remove every value
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+    }
+
+    /// Iterate over prefectures and their values in JIS X 0401 code order
+    pub fn iter(&self) -> impl Iterator<Item = (Prefecture, &V)> {
+        Prefecture::VARIANTS
+            .into_iter()
+            .zip(self.slots.iter())
+            .filter_map(|(pref, slot)| slot.as_ref().map(|value| (pref, value)))
+    }
+
+    /// Iterate over prefectures and mutable values in JIS X 0401 code order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Prefecture, &mut V)> {
+        Prefecture::VARIANTS
+            .into_iter()
+            .zip(self.slots.iter_mut())
+            .filter_map(|(pref, slot)| slot.as_mut().map(|value| (pref, value)))
+    }
+
+    /// Iterate over prefectures with a value in JIS X 0401 code order
+    pub fn keys(&self) -> impl Iterator<Item = Prefecture> + '_ {
+        self.iter().map(|(pref, _)| pref)
+    }
+
+    /// Iterate over values in JIS X 0401 code order of their prefectures
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+fn index(prefecture: Prefecture) -> usize {
+    prefecture.jis_x_0401_code() as usize - 1
+}
+
+impl<V> Default for PrefectureMap<V> {
+    fn default() -> PrefectureMap<V> {
+        PrefectureMap::new()
+    }
+}
+
+impl<V> Index<Prefecture> for PrefectureMap<V> {
+    type Output = V;
+
+    /// Return the value of a prefecture
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefecture has no value.
+    fn index(&self, prefecture: Prefecture) -> &V {
+        self.get(prefecture).expect("no value for prefecture")
+    }
+}
+
+impl<V> IndexMut<Prefecture> for PrefectureMap<V> {
+    /// Return the value of a prefecture mutably
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefecture has no value.
+    fn index_mut(&mut self, prefecture: Prefecture) -> &mut V {
+        self.get_mut(prefecture).expect("no value for prefecture")
+    }
+}
+
+impl<V> FromIterator<(Prefecture, V)> for PrefectureMap<V> {
+    fn from_iter<I: IntoIterator<Item = (Prefecture, V)>>(iter: I) -> PrefectureMap<V> {
+        let mut map = PrefectureMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<V> Extend<(Prefecture, V)> for PrefectureMap<V> {
+    fn extend<I: IntoIterator<Item = (Prefecture, V)>>(&mut self, iter: I) {
+        for (pref, value) in iter {
+            self.insert(pref, value);
+        }
+    }
+}
+
+impl<V> IntoIterator for PrefectureMap<V> {
+    type Item = (Prefecture, V);
+    type IntoIter = std::iter::FilterMap<
+        std::iter::Zip<
+            std::array::IntoIter<Prefecture, { Prefecture::COUNT }>,
+            std::array::IntoIter<Option<V>, { Prefecture::COUNT }>,
+        >,
+        fn((Prefecture, Option<V>)) -> Option<(Prefecture, V)>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Prefecture::VARIANTS
+            .into_iter()
+            .zip(self.slots)
+            .filter_map(|(pref, slot)| slot.map(|value| (pref, value)))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<V: ::serde::Serialize> ::serde::Serialize for PrefectureMap<V> {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V: ::serde::Deserialize<'de>> ::serde::Deserialize<'de> for PrefectureMap<V> {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use std::fmt;
+        use std::marker::PhantomData;
+
+        struct MapVisitor<V>(PhantomData<V>);
+
+        impl<'de, V: ::serde::Deserialize<'de>> ::serde::de::Visitor<'de> for MapVisitor<V> {
+            type Value = PrefectureMap<V>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map keyed by prefecture")
+            }
+
+            fn visit_map<A: ::serde::de::MapAccess<'de>>(
+                self,
+                mut access: A,
+            ) -> Result<PrefectureMap<V>, A::Error> {
+                let mut map = PrefectureMap::new();
+                while let Some((pref, value)) = access.next_entry()? {
+                    map.insert(pref, value);
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_tests() {
+        let mut map = PrefectureMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert(Prefecture::Okinawa, "a"), None);
+        assert_eq!(map.insert(Prefecture::Hokkaido, "b"), None);
+        assert_eq!(map.insert(Prefecture::Okinawa, "c"), Some("a"));
+        assert_eq!(map.len(), 2);
+        assert!(map.contains_key(Prefecture::Hokkaido));
+        assert!(!map.contains_key(Prefecture::Tokyo));
+        assert_eq!(map.get(Prefecture::Okinawa), Some(&"c"));
+        assert_eq!(map.remove(Prefecture::Okinawa), Some("c"));
+        assert_eq!(map.get(Prefecture::Okinawa), None);
+        map.clear();
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn iter_tests() {
+        let mut map: PrefectureMap<u32> = Prefecture::VARIANTS
+            .into_iter()
+            .rev()
+            .map(|pref| (pref, pref.jis_x_0401_code()))
+            .collect();
+        map.iter_mut().for_each(|(_, value)| *value *= 2);
+        assert!(map.keys().eq(Prefecture::VARIANTS));
+        assert!(map
+            .values()
+            .copied()
+            .eq(Prefecture::iter_codes().map(|c| c * 2)));
+        assert!(map
+            .into_iter()
+            .map(|(pref, _)| pref)
+            .eq(Prefecture::VARIANTS));
+    }
+
+    #[test]
+    fn index_tests() {
+        let mut map = PrefectureMap::new();
+        map.insert(Prefecture::Tokyo, 1);
+        map[Prefecture::Tokyo] += 1;
+        assert_eq!(map[Prefecture::Tokyo], 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "no value for prefecture")]
+    fn index_missing_tests() {
+        let map: PrefectureMap<u32> = PrefectureMap::new();
+        let _ = map[Prefecture::Tokyo];
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_tests() {
+        let map: PrefectureMap<u32> = [(Prefecture::Osaka, 2), (Prefecture::Tokyo, 1)]
+            .into_iter()
+            .collect();
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"Tokyo":1,"Osaka":2}"#);
+        assert_eq!(
+            serde_json::from_str::<PrefectureMap<u32>>(&json).unwrap(),
+            map
+        );
+        assert!(serde_json::from_str::<PrefectureMap<u32>>(r#"{"Tokio":1}"#).is_err());
+    }
+}
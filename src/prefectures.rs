@@ -11,21 +11,34 @@
 //! assert_eq!(tokyo.as_ref().unwrap().kanji(), "東京都");
 //! assert_eq!(tokyo.as_ref().unwrap().kanji_short(), "東京");
 //! assert_eq!(tokyo.as_ref().unwrap().kanji_short(), "東京");
+//! # #[cfg(feature = "kana")]
 //! assert_eq!(tokyo.as_ref().unwrap().hiragana(), "とうきょうと");
+//! # #[cfg(feature = "kana")]
 //! assert_eq!(tokyo.as_ref().unwrap().hiragana_short(), "とうきょう");
+//! # #[cfg(feature = "kana")]
 //! assert_eq!(tokyo.as_ref().unwrap().katakana(), "トウキョウト");
+//! # #[cfg(feature = "kana")]
 //! assert_eq!(tokyo.as_ref().unwrap().katakana_short(), "トウキョウ");
+//! # #[cfg(feature = "english")]
 //! assert_eq!(tokyo.as_ref().unwrap().english(), "Tokyo");
 //! ```
 
+use std::cmp;
 use std::collections::HashMap;
+use std::ops::Range;
 use std::str::FromStr;
 
-use crate::mapping::PREFECTURE_MAP;
-use crate::Error;
+#[cfg(feature = "i18n")]
+use crate::mapping::{LocalizedData, LOCALIZED_TABLE};
+use crate::mapping::{NAME_MAP, PREFECTURE_TABLE};
+use crate::regions::Region;
+use crate::{Error, ParseError};
+
+pub use crate::mapping::PrefectureData;
 
 /// A value of japanese prefecture
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub enum Prefecture {
     Hokkaido = 1,
     Aomori = 2,
@@ -77,6 +90,108 @@ pub enum Prefecture {
 }
 
 impl Prefecture {
+    /// Number of prefectures
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(Prefecture::COUNT, 47);
+    /// ```
+    pub const COUNT: usize = 47;
+
+    /// All prefectures, ordered by JIS X 0401 code
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(Prefecture::VARIANTS[0], Prefecture::Hokkaido);
+    /// assert_eq!(Prefecture::VARIANTS[Prefecture::COUNT - 1], Prefecture::Okinawa);
+    /// ```
+    pub const VARIANTS: [Prefecture; Prefecture::COUNT] = [
+        Prefecture::Hokkaido,
+        Prefecture::Aomori,
+        Prefecture::Iwate,
+        Prefecture::Miyagi,
+        Prefecture::Akita,
+        Prefecture::Yamagata,
+        Prefecture::Fukushima,
+        Prefecture::Ibaraki,
+        Prefecture::Tochigi,
+        Prefecture::Gunma,
+        Prefecture::Saitama,
+        Prefecture::Chiba,
+        Prefecture::Tokyo,
+        Prefecture::Kanagawa,
+        Prefecture::Niigata,
+        Prefecture::Toyama,
+        Prefecture::Ishikawa,
+        Prefecture::Fukui,
+        Prefecture::Yamanashi,
+        Prefecture::Nagano,
+        Prefecture::Gifu,
+        Prefecture::Shizuoka,
+        Prefecture::Aichi,
+        Prefecture::Mie,
+        Prefecture::Shiga,
+        Prefecture::Kyoto,
+        Prefecture::Osaka,
+        Prefecture::Hyogo,
+        Prefecture::Nara,
+        Prefecture::Wakayama,
+        Prefecture::Tottori,
+        Prefecture::Shimane,
+        Prefecture::Okayama,
+        Prefecture::Hiroshima,
+        Prefecture::Yamaguchi,
+        Prefecture::Tokushima,
+        Prefecture::Kagawa,
+        Prefecture::Ehime,
+        Prefecture::Kochi,
+        Prefecture::Fukuoka,
+        Prefecture::Saga,
+        Prefecture::Nagasaki,
+        Prefecture::Kumamoto,
+        Prefecture::Oita,
+        Prefecture::Miyazaki,
+        Prefecture::Kagoshima,
+        Prefecture::Okinawa,
+    ];
+
+    /// JIS X 0401 codes of all prefectures, aligned by index with [`Prefecture::VARIANTS`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(Prefecture::CODES[12], Prefecture::VARIANTS[12].jis_x_0401_code());
+    /// ```
+    pub const CODES: [u32; Prefecture::COUNT] = [
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+        26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+    ];
+
+    /// Iterate over the JIS X 0401 codes of all prefectures in ascending order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// let codes: Vec<u32> = Prefecture::iter_codes().collect();
+    ///
+    /// assert_eq!(codes.len(), Prefecture::COUNT);
+    /// assert_eq!(codes.first(), Some(&1));
+    /// assert_eq!(codes.last(), Some(&47));
+    /// ```
+    pub fn iter_codes() -> impl Iterator<Item = u32> {
+        Prefecture::CODES.into_iter()
+    }
+
     /// Returns a prefecture code defined by a JIS X 0401
     ///
     /// # Examples
@@ -88,10 +203,30 @@ impl Prefecture {
     ///
     /// assert_eq!(tokyo.jis_x_0401_code(), 13);
     /// ```
-    pub fn jis_x_0401_code(&self) -> u32 {
+    pub const fn jis_x_0401_code(&self) -> u32 {
         *self as u32
     }
 
+    /// Returns a prefecture for a JIS X 0401 code, usable in const contexts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// const TOKYO: Option<Prefecture> = Prefecture::from_code(13);
+    ///
+    /// assert_eq!(TOKYO, Some(Prefecture::Tokyo));
+    /// assert_eq!(Prefecture::from_code(0), None);
+    /// assert_eq!(Prefecture::from_code(48), None);
+    /// ```
+    pub const fn from_code(code: u8) -> Option<Prefecture> {
+        if code == 0 || code as usize > Prefecture::COUNT {
+            return None;
+        }
+        Some(Prefecture::VARIANTS[code as usize - 1])
+    }
+
     /// Returns a prefecture name in kanji
     ///
     /// # Examples
@@ -104,11 +239,7 @@ impl Prefecture {
     /// assert_eq!(tokyo.kanji(), "東京都".to_string());
     /// ```
     pub fn kanji(&self) -> String {
-        PREFECTURE_MAP
-            .get(self)
-            .expect("Unexpected error")
-            .kanji
-            .to_string()
+        self.names().kanji.to_string()
     }
 
     /// Return a short prefecture name in kanji
@@ -123,14 +254,7 @@ impl Prefecture {
     /// assert_eq!(tokyo.kanji_short(), "東京".to_string());
     /// ```
     pub fn kanji_short(&self) -> String {
-        let kanji = self.kanji();
-        let kanji_short = match self {
-            Prefecture::Hokkaido => kanji.as_str(),
-            Prefecture::Tokyo => kanji.trim_end_matches('都'),
-            Prefecture::Kyoto | Prefecture::Osaka => kanji.trim_end_matches('府'),
-            _ => kanji.trim_end_matches('県'),
-        };
-        String::from(kanji_short)
+        self.names().kanji_short.to_string()
     }
 
     /// Return a prefecture name in hiragana
@@ -144,12 +268,9 @@ impl Prefecture {
     ///
     /// assert_eq!(tokyo.hiragana(), "とうきょうと".to_string());
     /// ```
+    #[cfg(feature = "kana")]
     pub fn hiragana(&self) -> String {
-        PREFECTURE_MAP
-            .get(self)
-            .expect("Unexpected error")
-            .hiragana
-            .to_string()
+        self.names().hiragana.to_string()
     }
 
     /// Return a short prefecture name in hiragana
@@ -163,15 +284,9 @@ impl Prefecture {
     ///
     /// assert_eq!(tokyo.hiragana_short(), "とうきょう".to_string());
     /// ```
+    #[cfg(feature = "kana")]
     pub fn hiragana_short(&self) -> String {
-        let hiragana = self.hiragana();
-        let hiragana_short = match self {
-            Prefecture::Hokkaido => hiragana.as_str(),
-            Prefecture::Tokyo => hiragana.trim_end_matches('と'),
-            Prefecture::Kyoto | Prefecture::Osaka => hiragana.trim_end_matches('ふ'),
-            _ => hiragana.trim_end_matches("けん"),
-        };
-        String::from(hiragana_short)
+        self.names().hiragana_short.to_string()
     }
 
     /// Return a prefecture name in katakana
@@ -185,12 +300,9 @@ impl Prefecture {
     ///
     /// assert_eq!(tokyo.katakana(), "トウキョウト".to_string());
     /// ```
+    #[cfg(feature = "kana")]
     pub fn katakana(&self) -> String {
-        PREFECTURE_MAP
-            .get(self)
-            .expect("Unexpected error")
-            .katakana
-            .to_string()
+        self.names().katakana.to_string()
     }
 
     /// Return a prefecture name in katakana
@@ -204,15 +316,9 @@ impl Prefecture {
     ///
     /// assert_eq!(tokyo.katakana_short(), "トウキョウ".to_string());
     /// ```
+    #[cfg(feature = "kana")]
     pub fn katakana_short(&self) -> String {
-        let katakana = self.katakana();
-        let katakana_short = match self {
-            Prefecture::Hokkaido => katakana.as_str(),
-            Prefecture::Tokyo => katakana.trim_end_matches('ト'),
-            Prefecture::Kyoto | Prefecture::Osaka => katakana.trim_end_matches('フ'),
-            _ => katakana.trim_end_matches("ケン"),
-        };
-        String::from(katakana_short)
+        self.names().katakana_short.to_string()
     }
 
     /// Return a prefecture name in english
@@ -226,20 +332,264 @@ impl Prefecture {
     ///
     /// assert_eq!(tokyo.english(), "Tokyo");
     /// ```
+    #[cfg(feature = "english")]
     pub fn english(&self) -> String {
-        let english = PREFECTURE_MAP.get(self).expect("Unexpected error").english;
-        let mut chars = english.chars();
-        if let Some(fist_char) = chars.next() {
-            let capitalized_char = fist_char.to_uppercase().collect::<String>();
-            let rest_of_enlish = chars.as_str();
-            capitalized_char + rest_of_enlish
-        } else {
-            // Unreachable
-            panic!("Unexpected error");
+        self.names().english.to_string()
+    }
+
+    /// Return a URL slug of a prefecture
+    ///
+    /// The slug is the english name in lower-case ASCII (e.g. "tokyo"). Slugs are part of
+    /// the stable API and will not change between releases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(Prefecture::Tokyo.slug(), "tokyo");
+    /// assert_eq!(Prefecture::Hokkaido.slug(), "hokkaido");
+    /// ```
+    #[cfg(feature = "english")]
+    pub fn slug(&self) -> String {
+        self.names().english.to_ascii_lowercase()
+    }
+
+    /// Return a prefecture name in Kunrei-shiki romanization
+    ///
+    /// Long vowels are written with a circumflex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(Prefecture::Shizuoka.kunrei(), "Sizuoka");
+    /// assert_eq!(Prefecture::Kochi.kunrei(), "Kôti");
+    /// ```
+    #[cfg(feature = "english")]
+    pub fn kunrei(&self) -> String {
+        self.data().kunrei.to_string()
+    }
+
+    /// Return all name forms of a prefecture at once
+    ///
+    /// Unlike the individual accessors, this does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// let names = Prefecture::Tokyo.names();
+    ///
+    /// assert_eq!(names.kanji, "東京都");
+    /// assert_eq!(names.kanji_short, "東京");
+    /// # #[cfg(feature = "kana")]
+    /// assert_eq!(names.hiragana, "とうきょうと");
+    /// # #[cfg(feature = "kana")]
+    /// assert_eq!(names.hiragana_short, "とうきょう");
+    /// # #[cfg(feature = "kana")]
+    /// assert_eq!(names.katakana, "トウキョウト");
+    /// # #[cfg(feature = "kana")]
+    /// assert_eq!(names.katakana_short, "トウキョウ");
+    /// # #[cfg(feature = "english")]
+    /// assert_eq!(names.english, "Tokyo");
+    /// ```
+    pub fn names(&self) -> PrefectureNames {
+        let data = self.data();
+        let (kanji_suffix, hiragana_suffix, katakana_suffix) = self.suffixes();
+        #[cfg(not(feature = "kana"))]
+        let _ = (hiragana_suffix, katakana_suffix);
+        PrefectureNames {
+            kanji: data.kanji,
+            kanji_short: strip_suffix(data.kanji, kanji_suffix),
+            #[cfg(feature = "kana")]
+            hiragana: data.hiragana,
+            #[cfg(feature = "kana")]
+            hiragana_short: strip_suffix(data.hiragana, hiragana_suffix),
+            #[cfg(feature = "kana")]
+            katakana: data.katakana,
+            #[cfg(feature = "kana")]
+            katakana_short: strip_suffix(data.katakana, katakana_suffix),
+            #[cfg(feature = "english")]
+            english: data.english,
+        }
+    }
+
+    /// Return the record of a prefecture
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// let data = Prefecture::Kochi.data();
+    ///
+    /// # #[cfg(feature = "kana")]
+    /// assert_eq!(data.hiragana, "こうちけん");
+    /// # #[cfg(feature = "english")]
+    /// assert_eq!(data.english, "Kochi");
+    /// # #[cfg(feature = "english")]
+    /// assert_eq!(data.kunrei, "Kôti");
+    /// ```
+    pub fn data(&self) -> &'static PrefectureData {
+        &TABLE[*self as usize - 1]
+    }
+
+    /// Return the ISO 3166-2 code of a prefecture
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// assert_eq!(Prefecture::Tokyo.iso_code(), "JP-13");
+    /// assert_eq!(Prefecture::Hokkaido.iso_code(), "JP-01");
+    /// ```
+    pub fn iso_code(&self) -> String {
+        format!("JP-{:02}", self.jis_x_0401_code())
+    }
+}
+
+impl Prefecture {
+    /// Suffixes dropped from the kanji, hiragana and katakana names to form the short names
+    pub(crate) const fn suffixes(&self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Prefecture::Hokkaido => ("", "", ""),
+            Prefecture::Tokyo => ("都", "と", "ト"),
+            Prefecture::Kyoto | Prefecture::Osaka => ("府", "ふ", "フ"),
+            _ => ("県", "けん", "ケン"),
         }
     }
 }
 
+#[cfg(feature = "i18n")]
+impl Prefecture {
+    /// Return a prefecture name in simplified Chinese
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// let tokyo = Prefecture::Tokyo;
+    ///
+    /// assert_eq!(tokyo.name_zh_hans(), "东京都".to_string());
+    /// ```
+    pub fn name_zh_hans(&self) -> String {
+        self.localized().zh_hans.to_string()
+    }
+
+    /// Return a prefecture name in traditional Chinese
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// let tokyo = Prefecture::Tokyo;
+    ///
+    /// assert_eq!(tokyo.name_zh_hant(), "東京都".to_string());
+    /// ```
+    pub fn name_zh_hant(&self) -> String {
+        self.localized().zh_hant.to_string()
+    }
+
+    /// Return a prefecture name in Korean
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    ///
+    /// let tokyo = Prefecture::Tokyo;
+    ///
+    /// assert_eq!(tokyo.name_ko(), "도쿄도".to_string());
+    /// ```
+    pub fn name_ko(&self) -> String {
+        self.localized().ko.to_string()
+    }
+
+    fn localized(&self) -> &'static LocalizedData {
+        &LOCALIZED_TABLE[self.jis_x_0401_code() as usize - 1]
+    }
+
+    /// Suffixes dropped from the simplified Chinese, traditional Chinese and Korean names
+    /// to form the short names
+    fn localized_suffixes(&self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Prefecture::Hokkaido => ("", "", ""),
+            Prefecture::Tokyo => ("都", "都", "도"),
+            Prefecture::Kyoto | Prefecture::Osaka => ("府", "府", "부"),
+            _ => ("县", "縣", "현"),
+        }
+    }
+}
+
+fn strip_suffix(name: &'static str, suffix: &str) -> &'static str {
+    name.strip_suffix(suffix).unwrap_or(name)
+}
+
+/// Records of every prefecture, ordered by JIS X 0401 code
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::prefectures::{self, Prefecture};
+///
+/// let tokyo = &prefectures::TABLE[12];
+///
+/// assert_eq!(tokyo.prefecture, Prefecture::Tokyo);
+/// assert_eq!(tokyo.kanji, "東京都");
+/// # #[cfg(feature = "english")]
+/// assert_eq!(tokyo.kunrei, "Tôkyô");
+/// ```
+pub static TABLE: [PrefectureData; Prefecture::COUNT] = PREFECTURE_TABLE;
+
+/// All name forms of a prefecture
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PrefectureNames {
+    /// Name in kanji (e.g. "東京都")
+    pub kanji: &'static str,
+    /// Short name in kanji (e.g. "東京")
+    pub kanji_short: &'static str,
+    /// Name in hiragana (e.g. "とうきょうと")
+    #[cfg(feature = "kana")]
+    pub hiragana: &'static str,
+    /// Short name in hiragana (e.g. "とうきょう")
+    #[cfg(feature = "kana")]
+    pub hiragana_short: &'static str,
+    /// Name in katakana (e.g. "トウキョウト")
+    #[cfg(feature = "kana")]
+    pub katakana: &'static str,
+    /// Short name in katakana (e.g. "トウキョウ")
+    #[cfg(feature = "kana")]
+    pub katakana_short: &'static str,
+    /// Name in english (e.g. "Tokyo")
+    #[cfg(feature = "english")]
+    pub english: &'static str,
+}
+
+impl PrefectureNames {
+    /// Return every name form compiled in, kanji first
+    pub(crate) fn forms(&self) -> Vec<&'static str> {
+        #[allow(unused_mut)]
+        let mut forms = vec![self.kanji, self.kanji_short];
+        #[cfg(feature = "kana")]
+        forms.extend([
+            self.hiragana,
+            self.hiragana_short,
+            self.katakana,
+            self.katakana_short,
+        ]);
+        #[cfg(feature = "english")]
+        forms.push(self.english);
+        forms
+    }
+}
+
 /// Find a prefecture by JIS X 0401 code
 ///
 /// # Examples
@@ -248,141 +598,1008 @@ impl Prefecture {
 /// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
 ///
 /// assert_eq!(prefectures::find_by_code(13), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find_by_code(100), Err(Error::InvalidPrefectureCode(100)));
+/// assert_eq!(
+///     prefectures::find_by_code(100),
+///     Err(Error::CodeOutOfRange { code: 100, min: 1, max: 47 })
+/// );
 /// ```
 pub fn find_by_code(code: u32) -> Result<Prefecture, Error> {
-    let mut map: HashMap<u32, Prefecture> = HashMap::new();
-    PREFECTURE_MAP.iter().for_each(|(pref, _)| {
-        map.insert(pref.jis_x_0401_code(), *pref);
+    u8::try_from(code)
+        .ok()
+        .and_then(Prefecture::from_code)
+        .ok_or_else(|| Error::code_out_of_range(code.into()))
+}
+
+/// Find a prefecture by JIS X 0401 code written as a string
+///
+/// Accepts zero-padded codes and full-width digits, as found in CSV exports from
+/// legacy systems. Surrounding whitespace is ignored. Codes too large for a `u64` are
+/// reported as [`Error::MalformedPrefectureCode`].
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(prefectures::find_by_code_str("13"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_code_str("01"), Ok(Prefecture::Hokkaido));
+/// assert_eq!(prefectures::find_by_code_str("１３"), Ok(Prefecture::Tokyo));
+/// assert_eq!(
+///     prefectures::find_by_code_str("48"),
+///     Err(Error::CodeOutOfRange { code: 48, min: 1, max: 47 })
+/// );
+/// assert_eq!(prefectures::find_by_code_str("x1"), Err(Error::MalformedPrefectureCode { input: "x1".to_string() }));
+/// ```
+pub fn find_by_code_str<T: AsRef<str> + ToString>(code: T) -> Result<Prefecture, Error> {
+    let digits = code
+        .as_ref()
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32),
+            '0'..='9' => Some(c),
+            _ => None,
+        })
+        .collect::<Option<String>>()
+        .filter(|digits| !digits.is_empty())
+        .ok_or_else(|| Error::MalformedPrefectureCode {
+            input: code.to_string(),
+        })?;
+    // Only overflow can fail here, as every char is a digit
+    let code = digits
+        .parse::<u64>()
+        .map_err(|_| Error::MalformedPrefectureCode {
+            input: code.to_string(),
+        })?;
+    u32::try_from(code)
+        .map_err(|_| Error::code_out_of_range(code))
+        .and_then(find_by_code)
+}
+
+/// Find a prefecture by ISO 3166-2 code
+///
+/// The "JP-" prefix is matched case-insensitively and must be followed by exactly two
+/// ASCII digits.
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(prefectures::find_by_iso_code("JP-13"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_iso_code("jp-01"), Ok(Prefecture::Hokkaido));
+/// assert_eq!(prefectures::find_by_iso_code("JP-48"), Err(Error::InvalidIsoCode { code: "JP-48".to_string() }));
+/// assert_eq!(prefectures::find_by_iso_code("JP-1"), Err(Error::InvalidIsoCode { code: "JP-1".to_string() }));
+/// ```
+pub fn find_by_iso_code<T: AsRef<str> + ToString>(code: T) -> Result<Prefecture, Error> {
+    let s = code.as_ref();
+    s.get(..3)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("JP-"))
+        .map(|_| &s[3..])
+        .filter(|digits| digits.len() == 2 && digits.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|digits| digits.parse::<u8>().ok())
+        .and_then(Prefecture::from_code)
+        .ok_or_else(|| Error::InvalidIsoCode {
+            code: code.to_string(),
+        })
+}
+
+/// Find a prefecture by name in kanji
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(prefectures::find_by_kanji("東京都"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_kanji("東京"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_kanji("東京県"), Err(Error::InvalidPrefectureName { name: "東京県".to_string() }));
+/// ```
+pub fn find_by_kanji<T: AsRef<str> + ToString>(kanji: T) -> Result<Prefecture, Error> {
+    let mut map: HashMap<String, Prefecture> = HashMap::new();
+    Prefecture::VARIANTS.iter().for_each(|pref| {
+        map.insert(pref.kanji(), *pref);
+        map.insert(pref.kanji_short(), *pref);
+    });
+    map.get(kanji.as_ref())
+        .ok_or_else(|| Error::InvalidPrefectureName {
+            name: kanji.to_string(),
+        })
+        .copied()
+}
+
+/// Find a prefecture by name in hiragana
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(prefectures::find_by_hiragana("とうきょうと"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_hiragana("とうきょう"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_hiragana("とうきょうけん"), Err(Error::InvalidPrefectureName { name: "とうきょうけん".to_string() }));
+/// ```
+#[cfg(feature = "kana")]
+pub fn find_by_hiragana<T: AsRef<str> + ToString>(hiragana: T) -> Result<Prefecture, Error> {
+    let mut map: HashMap<String, Prefecture> = HashMap::new();
+    Prefecture::VARIANTS.iter().for_each(|pref| {
+        map.insert(pref.hiragana(), *pref);
+        map.insert(pref.hiragana_short(), *pref);
     });
-    map.get(&code)
-        .ok_or_else(|| Error::InvalidPrefectureCode(code))
+    map.get(hiragana.as_ref())
+        .ok_or_else(|| Error::InvalidPrefectureName {
+            name: hiragana.to_string(),
+        })
+        .copied()
+}
+
+/// Find a prefecture by name in katakana
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(prefectures::find_by_katakana("トウキョウト"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_katakana("トウキョウ"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_katakana("トウキョウケン"), Err(Error::InvalidPrefectureName { name: "トウキョウケン".to_string() }));
+/// ```
+#[cfg(feature = "kana")]
+pub fn find_by_katakana<T: AsRef<str> + ToString>(katakana: T) -> Result<Prefecture, Error> {
+    let mut map: HashMap<String, Prefecture> = HashMap::new();
+    Prefecture::VARIANTS.iter().for_each(|pref| {
+        map.insert(pref.katakana(), *pref);
+        map.insert(pref.katakana_short(), *pref);
+    });
+    map.get(katakana.as_ref())
+        .ok_or_else(|| Error::InvalidPrefectureName {
+            name: katakana.to_string(),
+        })
+        .copied()
+}
+
+/// Find a prefecture by name in english
+///
+/// Both Hepburn (e.g. "Shizuoka") and Kunrei-shiki (e.g. "Sizuoka") romanizations are
+/// accepted, case-insensitively and with or without circumflexes on long vowels.
+/// Passport-style and wāpuro spellings of long vowels (e.g. "Ohsaka", "Tookyoo", "Kouchi",
+/// "Ōita") and "m" for the syllabic n (e.g. "Gumma") are accepted as well.
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(prefectures::find_by_english("tokyo"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_english("Tokyo"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_english("tOkYo"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_english("Tôkyô"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_english("Kôti"), Ok(Prefecture::Kochi));
+/// assert_eq!(prefectures::find_by_english("Ohsaka"), Ok(Prefecture::Osaka));
+/// assert_eq!(prefectures::find_by_english("Gumma"), Ok(Prefecture::Gunma));
+/// assert_eq!(prefectures::find_by_english("tokyo~~~"), Err(Error::InvalidPrefectureName { name: "tokyo~~~".to_string() }));
+/// ```
+#[cfg(feature = "english")]
+pub fn find_by_english<T: AsRef<str> + ToString>(english: T) -> Result<Prefecture, Error> {
+    let romaji = fold_romaji(english.as_ref());
+    TABLE
+        .iter()
+        .find(|data| fold_romaji(data.english) == romaji || fold_romaji(data.kunrei) == romaji)
+        .map(|data| data.prefecture)
+        .ok_or_else(|| Error::InvalidPrefectureName {
+            name: english.to_string(),
+        })
+}
+
+/// Find a prefecture by URL slug
+///
+/// Only the canonical slug returned by [`Prefecture::slug`] is accepted. Upper-case
+/// letters, surrounding whitespace and alternative spellings are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(prefectures::find_by_slug("tokyo"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_slug("Tokyo"), Err(Error::InvalidPrefectureName { name: "Tokyo".to_string() }));
+/// ```
+#[cfg(feature = "english")]
+pub fn find_by_slug<T: AsRef<str> + ToString>(slug: T) -> Result<Prefecture, Error> {
+    Prefecture::VARIANTS
+        .into_iter()
+        .find(|pref| pref.slug() == slug.as_ref())
+        .ok_or_else(|| Error::InvalidPrefectureName {
+            name: slug.to_string(),
+        })
+}
+
+/// Lower-case romaji and fold long-vowel and syllabic-n spellings
+#[cfg(feature = "english")]
+fn fold_romaji(romaji: &str) -> String {
+    fold_romaji_chars(romaji).collect()
+}
+
+/// Lower-case romaji and fold long-vowel and syllabic-n spellings, char by char
+///
+/// Circumflexes and macrons are dropped, a long o written as "oo", "ou" or "oh" is
+/// shortened to "o" (one letter at most), and "m" before "b", "m" or "p" is read as "n".
+#[cfg(feature = "english")]
+pub(crate) fn fold_romaji_chars(romaji: &str) -> impl Iterator<Item = char> + '_ {
+    let mut chars = romaji
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'â' | 'ā' => 'a',
+            'î' | 'ī' => 'i',
+            'û' | 'ū' => 'u',
+            'ê' | 'ē' => 'e',
+            'ô' | 'ō' => 'o',
+            _ => c,
+        })
+        .peekable();
+    let mut prev = None;
+    std::iter::from_fn(move || loop {
+        let c = chars.next()?;
+        let c = match c {
+            // Only one letter lengthens a vowel, so "ooo" is not read as "o"
+            'o' | 'u' | 'h' if prev == Some('o') => {
+                prev = None;
+                continue;
+            }
+            'm' if matches!(chars.peek(), Some('b' | 'm' | 'p')) => 'n',
+            _ => c,
+        };
+        prev = Some(c);
+        return Some(c);
+    })
+}
+
+/// Find a prefecture by name in simplified Chinese
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(prefectures::find_by_zh_hans("东京都"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_zh_hans("东京"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_zh_hans("东京县"), Err(Error::InvalidPrefectureName { name: "东京县".to_string() }));
+/// ```
+#[cfg(feature = "i18n")]
+pub fn find_by_zh_hans<T: AsRef<str> + ToString>(zh_hans: T) -> Result<Prefecture, Error> {
+    find_by_localized(zh_hans, |pref| {
+        (pref.localized().zh_hans, pref.localized_suffixes().0)
+    })
+}
+
+/// Find a prefecture by name in traditional Chinese
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(prefectures::find_by_zh_hant("靜岡縣"), Ok(Prefecture::Shizuoka));
+/// assert_eq!(prefectures::find_by_zh_hant("靜岡"), Ok(Prefecture::Shizuoka));
+/// assert_eq!(prefectures::find_by_zh_hant("静冈县"), Err(Error::InvalidPrefectureName { name: "静冈县".to_string() }));
+/// ```
+#[cfg(feature = "i18n")]
+pub fn find_by_zh_hant<T: AsRef<str> + ToString>(zh_hant: T) -> Result<Prefecture, Error> {
+    find_by_localized(zh_hant, |pref| {
+        (pref.localized().zh_hant, pref.localized_suffixes().1)
+    })
+}
+
+/// Find a prefecture by name in Korean
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(prefectures::find_by_ko("도쿄도"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_ko("도쿄"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_by_ko("도쿄현"), Err(Error::InvalidPrefectureName { name: "도쿄현".to_string() }));
+/// ```
+#[cfg(feature = "i18n")]
+pub fn find_by_ko<T: AsRef<str> + ToString>(ko: T) -> Result<Prefecture, Error> {
+    find_by_localized(ko, |pref| {
+        (pref.localized().ko, pref.localized_suffixes().2)
+    })
+}
+
+/// Find a prefecture whose localized name, or that name without its suffix, matches
+#[cfg(feature = "i18n")]
+fn find_by_localized<T, F>(name: T, form: F) -> Result<Prefecture, Error>
+where
+    T: AsRef<str> + ToString,
+    F: Fn(&Prefecture) -> (&'static str, &'static str),
+{
+    Prefecture::VARIANTS
+        .iter()
+        .find(|pref| {
+            let (full, suffix) = form(pref);
+            full == name.as_ref() || strip_suffix(full, suffix) == name.as_ref()
+        })
         .copied()
+        .ok_or_else(|| Error::InvalidPrefectureName {
+            name: name.to_string(),
+        })
+}
+
+/// Find a prefecture by name
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(prefectures::find("東京都"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find("東京"), Ok(Prefecture::Tokyo));
+/// # #[cfg(feature = "kana")]
+/// assert_eq!(prefectures::find("とうきょうと"), Ok(Prefecture::Tokyo));
+/// # #[cfg(feature = "kana")]
+/// assert_eq!(prefectures::find("とうきょう"), Ok(Prefecture::Tokyo));
+/// # #[cfg(feature = "kana")]
+/// assert_eq!(prefectures::find("トウキョウト"), Ok(Prefecture::Tokyo));
+/// # #[cfg(feature = "kana")]
+/// assert_eq!(prefectures::find("トウキョウ"), Ok(Prefecture::Tokyo));
+/// # #[cfg(feature = "english")]
+/// assert_eq!(prefectures::find("tokyo"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find("none"), Err(Error::InvalidPrefectureName { name: "none".to_string() }));
+/// ```
+pub fn find<T: AsRef<str>>(s: T) -> Result<Prefecture, Error> {
+    Prefecture::from_str(s.as_ref())
+}
+
+/// Parse a prefecture name without allocating
+///
+/// Accepts the same name forms as [`find`], but the error borrows the input instead of
+/// copying it, which keeps bulk validation free of allocations on both paths.
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, ParseError};
+///
+/// assert_eq!(prefectures::parse("東京都"), Ok(Prefecture::Tokyo));
+/// # #[cfg(feature = "english")]
+/// assert_eq!(prefectures::parse("TOKYO"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::parse("none"), Err(ParseError::InvalidPrefectureName { name: "none" }));
+/// ```
+pub fn parse(s: &str) -> Result<Prefecture, ParseError<'_>> {
+    let found = NAME_MAP.get(s).copied();
+    #[cfg(feature = "english")]
+    let found = found.or_else(|| {
+        TABLE
+            .iter()
+            .find(|data| data.english.eq_ignore_ascii_case(s))
+            .map(|data| data.prefecture)
+    });
+    found.ok_or(ParseError::InvalidPrefectureName { name: s })
+}
+
+/// Find prefectures for each name in a batch
+///
+/// Accepts the same name forms as [`find`]. The lookup table is built once and shared
+/// across the whole batch, and results are returned in input order.
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+///
+/// assert_eq!(
+///     prefectures::find_all(["東京都", "大阪", "none"]),
+///     vec![
+///         Ok(Prefecture::Tokyo),
+///         Ok(Prefecture::Osaka),
+///         Err(Error::InvalidPrefectureName { name: "none".to_string() }),
+///     ]
+/// );
+/// ```
+pub fn find_all<I, T>(names: I) -> Vec<Result<Prefecture, Error>>
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<str>,
+{
+    names
+        .into_iter()
+        .map(|name| Prefecture::from_str(name.as_ref()))
+        .collect()
+}
+
+/// Compare two prefecture names by JIS X 0401 code
+///
+/// Names are resolved in the same way as [`find`]. Names that are not a prefecture
+/// are ordered after every prefecture and compare equal to each other.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use jp_prefecture::prefectures;
+///
+/// assert_eq!(prefectures::cmp_by_code("北海道", "沖縄県"), Ordering::Less);
+/// # #[cfg(feature = "english")]
+/// assert_eq!(prefectures::cmp_by_code("tokyo", "東京都"), Ordering::Equal);
+/// assert_eq!(prefectures::cmp_by_code("none", "沖縄県"), Ordering::Greater);
+/// ```
+pub fn cmp_by_code<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> cmp::Ordering {
+    sort_key(a.as_ref()).cmp(&sort_key(b.as_ref()))
+}
+
+/// Sort prefecture names by JIS X 0401 code
+///
+/// Uses the same ordering as [`cmp_by_code`]. The sort is stable, so names that are not
+/// a prefecture keep their relative order at the end.
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::prefectures;
+///
+/// let mut names = vec!["沖縄県", "none", "東京", "北海道"];
+/// prefectures::sort_names(&mut names);
+///
+/// assert_eq!(names, vec!["北海道", "東京", "沖縄県", "none"]);
+/// ```
+pub fn sort_names<T: AsRef<str>>(names: &mut [T]) {
+    names.sort_by_cached_key(|name| sort_key(name.as_ref()));
+}
+
+fn sort_key(name: &str) -> u32 {
+    find(name).map_or(u32::MAX, |pref| pref.jis_x_0401_code())
+}
+
+/// Named orderings of all prefectures
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::prefectures::{Prefecture, SortOrder};
+///
+/// let ordering = SortOrder::GojuonReading;
+///
+/// assert_eq!(ordering.prefectures()[0], Prefecture::Aichi);
+/// assert_eq!(ordering.sort_index(Prefecture::Wakayama), 46);
+///
+/// let mut prefectures = vec![Prefecture::Tokyo, Prefecture::Osaka, Prefecture::Hokkaido];
+/// prefectures.sort_by_key(|pref| ordering.sort_index(*pref));
+///
+/// assert_eq!(prefectures, vec![Prefecture::Osaka, Prefecture::Tokyo, Prefecture::Hokkaido]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SortOrder {
+    /// By JIS X 0401 code, from Hokkaido to Okinawa
+    Jis,
+    /// By latitude of the prefectural office, from Hokkaido to Okinawa
+    ///
+    /// Unlike [`SortOrder::Jis`], prefectures are not grouped by region (e.g. Niigata
+    /// comes before Fukushima and Tottori before Kanagawa). Latitudes are those of the
+    /// prefectural offices published by the Geospatial Information Authority of Japan
+    /// (国土地理院) in 「都道府県庁間の距離」.
+    NorthToSouthGeographic,
+    /// By reading in gojūon order, from Aichi (あいち) to Wakayama (わかやま)
+    GojuonReading,
+}
+
+impl SortOrder {
+    /// Return all prefectures in this ordering
+    pub fn prefectures(&self) -> &'static [Prefecture; Prefecture::COUNT] {
+        match self {
+            SortOrder::Jis => &Prefecture::VARIANTS,
+            SortOrder::NorthToSouthGeographic => &NORTH_TO_SOUTH_ORDER,
+            SortOrder::GojuonReading => &GOJUON_ORDER,
+        }
+    }
+
+    /// Return the zero-based position of a prefecture in this ordering
+    pub fn sort_index(&self, prefecture: Prefecture) -> usize {
+        match self {
+            SortOrder::Jis => prefecture.jis_x_0401_code() as usize - 1,
+            _ => self
+                .prefectures()
+                .iter()
+                .position(|pref| *pref == prefecture)
+                .expect("Unexpected error"),
+        }
+    }
+
+    /// Compare two prefectures by their position in this ordering
+    pub fn compare(&self, a: Prefecture, b: Prefecture) -> cmp::Ordering {
+        self.sort_index(a).cmp(&self.sort_index(b))
+    }
+}
+
+/// Prefectures by latitude of their office, as published by the Geospatial Information
+/// Authority of Japan
+static NORTH_TO_SOUTH_ORDER: [Prefecture; Prefecture::COUNT] = [
+    Prefecture::Hokkaido,
+    Prefecture::Aomori,
+    Prefecture::Akita,
+    Prefecture::Iwate,
+    Prefecture::Miyagi,
+    Prefecture::Yamagata,
+    Prefecture::Niigata,
+    Prefecture::Fukushima,
+    Prefecture::Toyama,
+    Prefecture::Nagano,
+    Prefecture::Ishikawa,
+    Prefecture::Tochigi,
+    Prefecture::Gunma,
+    Prefecture::Ibaraki,
+    Prefecture::Fukui,
+    Prefecture::Saitama,
+    Prefecture::Tokyo,
+    Prefecture::Yamanashi,
+    Prefecture::Chiba,
+    Prefecture::Tottori,
+    Prefecture::Shimane,
+    Prefecture::Kanagawa,
+    Prefecture::Gifu,
+    Prefecture::Aichi,
+    Prefecture::Kyoto,
+    Prefecture::Shiga,
+    Prefecture::Shizuoka,
+    Prefecture::Mie,
+    Prefecture::Hyogo,
+    Prefecture::Osaka,
+    Prefecture::Nara,
+    Prefecture::Okayama,
+    Prefecture::Hiroshima,
+    Prefecture::Kagawa,
+    Prefecture::Wakayama,
+    Prefecture::Yamaguchi,
+    Prefecture::Tokushima,
+    Prefecture::Ehime,
+    Prefecture::Fukuoka,
+    Prefecture::Kochi,
+    Prefecture::Saga,
+    Prefecture::Oita,
+    Prefecture::Kumamoto,
+    Prefecture::Nagasaki,
+    Prefecture::Miyazaki,
+    Prefecture::Kagoshima,
+    Prefecture::Okinawa,
+];
+
+static GOJUON_ORDER: [Prefecture; Prefecture::COUNT] = [
+    Prefecture::Aichi,
+    Prefecture::Aomori,
+    Prefecture::Akita,
+    Prefecture::Ishikawa,
+    Prefecture::Ibaraki,
+    Prefecture::Iwate,
+    Prefecture::Ehime,
+    Prefecture::Oita,
+    Prefecture::Osaka,
+    Prefecture::Okayama,
+    Prefecture::Okinawa,
+    Prefecture::Kagawa,
+    Prefecture::Kagoshima,
+    Prefecture::Kanagawa,
+    Prefecture::Gifu,
+    Prefecture::Kyoto,
+    Prefecture::Kumamoto,
+    Prefecture::Gunma,
+    Prefecture::Kochi,
+    Prefecture::Saitama,
+    Prefecture::Saga,
+    Prefecture::Shiga,
+    Prefecture::Shizuoka,
+    Prefecture::Shimane,
+    Prefecture::Chiba,
+    Prefecture::Tokyo,
+    Prefecture::Tokushima,
+    Prefecture::Tochigi,
+    Prefecture::Tottori,
+    Prefecture::Toyama,
+    Prefecture::Nagasaki,
+    Prefecture::Nagano,
+    Prefecture::Nara,
+    Prefecture::Niigata,
+    Prefecture::Hyogo,
+    Prefecture::Hiroshima,
+    Prefecture::Fukui,
+    Prefecture::Fukuoka,
+    Prefecture::Fukushima,
+    Prefecture::Hokkaido,
+    Prefecture::Mie,
+    Prefecture::Miyagi,
+    Prefecture::Miyazaki,
+    Prefecture::Yamagata,
+    Prefecture::Yamaguchi,
+    Prefecture::Yamanashi,
+    Prefecture::Wakayama,
+];
+
+/// Group prefectures by region
+///
+/// Regions come from Hokkaido to Kyushu, and prefectures in each region are ordered by
+/// JIS X 0401 code.
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::prefectures::{self, Prefecture};
+/// use jp_prefecture::regions::Region;
+///
+/// let (region, prefectures) = prefectures::group_by_region().nth(6).unwrap();
+///
+/// assert_eq!(region, Region::Shikoku);
+/// assert_eq!(
+///     prefectures,
+///     &[Prefecture::Tokushima, Prefecture::Kagawa, Prefecture::Ehime, Prefecture::Kochi]
+/// );
+/// ```
+pub fn group_by_region() -> impl Iterator<Item = (Region, &'static [Prefecture])> {
+    Region::VARIANTS
+        .into_iter()
+        .map(|region| (region, region.prefectures()))
+}
+
+/// Iterate over all prefectures in region order, then by JIS X 0401 code within a region
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::prefectures::{self, Prefecture};
+///
+/// let prefectures: Vec<Prefecture> = prefectures::iter_by_region().collect();
+///
+/// assert_eq!(prefectures.len(), Prefecture::COUNT);
+/// assert_eq!(prefectures[0], Prefecture::Hokkaido);
+/// assert_eq!(prefectures[46], Prefecture::Okinawa);
+/// ```
+pub fn iter_by_region() -> impl Iterator<Item = Prefecture> {
+    group_by_region().flat_map(|(_, prefectures)| prefectures.iter().copied())
+}
+
+/// Extract every prefecture mentioned in a text with its byte range
+///
+/// Names in kanji are matched from left to right without overlapping, and the longest
+/// name wins at each position, so "東京都" is read as Tokyo rather than containing
+/// "京都". Short names (e.g. "山口") are matched as well and may also be parts of
+/// other words such as family names.
+///
+/// # Examples
+///
+/// ```
+/// use jp_prefecture::prefectures::{self, Prefecture};
+///
+/// assert_eq!(
+///     prefectures::extract_all("東京都から京都府へ"),
+///     vec![(Prefecture::Tokyo, 0..9), (Prefecture::Kyoto, 15..24)]
+/// );
+/// ```
+pub fn extract_all<T: AsRef<str>>(text: T) -> Vec<(Prefecture, Range<usize>)> {
+    let text = text.as_ref();
+    let mut names: Vec<(&'static str, Prefecture)> = Prefecture::VARIANTS
+        .into_iter()
+        .flat_map(|pref| {
+            let names = pref.names();
+            [(names.kanji, pref), (names.kanji_short, pref)]
+        })
+        .collect();
+    names.sort_by_key(|(name, _)| cmp::Reverse(name.len()));
+    let mut mentions = Vec::new();
+    let mut start = 0;
+    while let Some(c) = text[start..].chars().next() {
+        match names
+            .iter()
+            .find(|(name, _)| text[start..].starts_with(name))
+        {
+            Some((name, pref)) => {
+                mentions.push((*pref, start..start + name.len()));
+                start += name.len();
+            }
+            None => start += c.len_utf8(),
+        }
+    }
+    mentions
 }
 
-/// Find a prefecture by name in kanji
+/// Search prefectures whose name starts with the given prefix
+///
+/// All name forms (kanji, hiragana, katakana and english) are matched, and english
+/// is matched case-insensitively. Results are ordered by JIS X 0401 code.
+/// An empty prefix matches nothing.
 ///
 /// # Examples
 ///
 /// ```
-/// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
+/// use jp_prefecture::prefectures::{self, Prefecture};
 ///
-/// assert_eq!(prefectures::find_by_kanji("東京都"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find_by_kanji("東京"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find_by_kanji("東京県"), Err(Error::InvalidPrefectureName("東京県".to_string())));
+/// # #[cfg(feature = "kana")]
+/// assert_eq!(
+///     prefectures::search_prefix("やま"),
+///     vec![Prefecture::Yamagata, Prefecture::Yamanashi, Prefecture::Yamaguchi]
+/// );
+/// # #[cfg(feature = "english")]
+/// assert_eq!(prefectures::search_prefix("Toky"), vec![Prefecture::Tokyo]);
+/// assert_eq!(prefectures::search_prefix("none"), vec![]);
 /// ```
-pub fn find_by_kanji<T: AsRef<str> + ToString>(kanji: T) -> Result<Prefecture, Error> {
-    let mut map: HashMap<String, Prefecture> = HashMap::new();
-    PREFECTURE_MAP.iter().for_each(|(pref, _)| {
-        map.insert(pref.kanji(), *pref);
-        map.insert(pref.kanji_short(), *pref);
-    });
-    map.get(kanji.as_ref())
-        .ok_or_else(|| Error::InvalidPrefectureName(kanji.to_string()))
+pub fn search_prefix<T: AsRef<str>>(prefix: T) -> Vec<Prefecture> {
+    let prefix = prefix.as_ref().to_lowercase();
+    if prefix.is_empty() {
+        return Vec::new();
+    }
+    Prefecture::VARIANTS
+        .iter()
+        .filter(|pref| {
+            pref.names()
+                .forms()
+                .iter()
+                .any(|name| starts_with_lowercase(name, &prefix))
+        })
         .copied()
+        .collect()
 }
 
-/// Find a prefecture by name in hiragana
+/// Whether `name` starts with `prefix` once lower-cased, without allocating
+fn starts_with_lowercase(name: &str, prefix: &str) -> bool {
+    let mut name = name.chars().flat_map(char::to_lowercase);
+    prefix.chars().all(|c| name.next() == Some(c))
+}
+
+/// Default maximum input length, in bytes, accepted by the lenient and fuzzy parsers
+pub const DEFAULT_MAX_INPUT_LEN: usize = 256;
+
+/// Search prefectures by a possibly misspelled or partial name
+///
+/// The query is compared against all name forms (english case-insensitively) by edit
+/// distance, and each candidate is scored from `0.0` to `1.0` by its closest name form.
+/// Candidates scoring at least `0.5` are returned, best match first.
+///
+/// Queries longer than [`DEFAULT_MAX_INPUT_LEN`] bytes are rejected with
+/// [`Error::InputTooLong`]; use [`search_fuzzy_with_max_len`] to choose another limit.
 ///
 /// # Examples
 ///
 /// ```
 /// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
 ///
-/// assert_eq!(prefectures::find_by_hiragana("とうきょうと"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find_by_hiragana("とうきょう"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find_by_hiragana("とうきょうけん"), Err(Error::InvalidPrefectureName("とうきょうけん".to_string())));
+/// # #[cfg(feature = "english")]
+/// let candidates = prefectures::search_fuzzy("tokio").unwrap();
+/// # #[cfg(feature = "english")]
+/// assert_eq!(candidates[0].0, Prefecture::Tokyo);
+///
+/// # #[cfg(feature = "kana")]
+/// let candidates = prefectures::search_fuzzy("かながわ").unwrap();
+/// # #[cfg(feature = "kana")]
+/// assert_eq!(candidates[0], (Prefecture::Kanagawa, 1.0));
+///
+/// assert!(prefectures::search_fuzzy("xxxxxxxx").unwrap().is_empty());
+/// assert_eq!(
+///     prefectures::search_fuzzy("x".repeat(1000)),
+///     Err(Error::InputTooLong { len: 1000, max: prefectures::DEFAULT_MAX_INPUT_LEN })
+/// );
 /// ```
-pub fn find_by_hiragana<T: AsRef<str> + ToString>(hiragana: T) -> Result<Prefecture, Error> {
-    let mut map: HashMap<String, Prefecture> = HashMap::new();
-    PREFECTURE_MAP.iter().for_each(|(pref, _)| {
-        map.insert(pref.hiragana(), *pref);
-        map.insert(pref.hiragana_short(), *pref);
-    });
-    map.get(hiragana.as_ref())
-        .ok_or_else(|| Error::InvalidPrefectureName(hiragana.to_string()))
-        .copied()
+pub fn search_fuzzy<T: AsRef<str>>(query: T) -> Result<Vec<(Prefecture, f32)>, Error> {
+    search_fuzzy_with_max_len(query, DEFAULT_MAX_INPUT_LEN)
 }
 
-/// Find a prefecture by name in katakana
+/// Search prefectures by a possibly misspelled or partial name, accepting queries of up to
+/// `max_len` bytes
+///
+/// Behaves like [`search_fuzzy`] otherwise.
 ///
 /// # Examples
 ///
 /// ```
 /// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
 ///
-/// assert_eq!(prefectures::find_by_katakana("トウキョウト"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find_by_katakana("トウキョウ"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find_by_katakana("トウキョウケン"), Err(Error::InvalidPrefectureName("トウキョウケン".to_string())));
+/// # #[cfg(feature = "english")]
+/// assert_eq!(prefectures::search_fuzzy_with_max_len("tokyo", 5).unwrap()[0].0, Prefecture::Tokyo);
+/// assert_eq!(
+///     prefectures::search_fuzzy_with_max_len("tokyo", 4),
+///     Err(Error::InputTooLong { len: 5, max: 4 })
+/// );
 /// ```
-pub fn find_by_katakana<T: AsRef<str> + ToString>(katakana: T) -> Result<Prefecture, Error> {
-    let mut map: HashMap<String, Prefecture> = HashMap::new();
-    PREFECTURE_MAP.iter().for_each(|(pref, _)| {
-        map.insert(pref.katakana(), *pref);
-        map.insert(pref.katakana_short(), *pref);
+pub fn search_fuzzy_with_max_len<T: AsRef<str>>(
+    query: T,
+    max_len: usize,
+) -> Result<Vec<(Prefecture, f32)>, Error> {
+    check_len(query.as_ref(), max_len)?;
+    let query = query.as_ref().to_lowercase();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut candidates: Vec<(Prefecture, f32)> = Prefecture::VARIANTS
+        .iter()
+        .map(|pref| {
+            let score = pref
+                .names()
+                .forms()
+                .iter()
+                .map(|name| similarity(&query, &name.to_lowercase()))
+                .fold(0.0, f32::max);
+            (*pref, score)
+        })
+        .filter(|(_, score)| *score >= FUZZY_THRESHOLD)
+        .collect();
+    candidates.sort_by(|(a, a_score), (b, b_score)| {
+        b_score
+            .total_cmp(a_score)
+            .then_with(|| a.jis_x_0401_code().cmp(&b.jis_x_0401_code()))
     });
-    map.get(katakana.as_ref())
-        .ok_or_else(|| Error::InvalidPrefectureName(katakana.to_string()))
-        .copied()
+    Ok(candidates)
 }
 
-/// Find a prefecture by name in english
+pub(crate) fn check_len(input: &str, max_len: usize) -> Result<(), Error> {
+    if input.len() > max_len {
+        return Err(Error::InputTooLong {
+            len: input.len(),
+            max: max_len,
+        });
+    }
+    Ok(())
+}
+
+/// Resolve the prefectures a name matched to a single one
+///
+/// Matching the same prefecture more than once is not ambiguous. Does not allocate unless
+/// the name is invalid or ambiguous.
+pub(crate) fn unique_match<I>(name: &str, matches: I) -> Result<Prefecture, Error>
+where
+    I: IntoIterator<Item = Prefecture>,
+{
+    let mut matches = matches.into_iter();
+    let first = matches.next().ok_or_else(|| Error::InvalidPrefectureName {
+        name: name.to_string(),
+    })?;
+    let mut others = Vec::new();
+    for pref in matches {
+        if pref != first && !others.contains(&pref) {
+            others.push(pref);
+        }
+    }
+    if others.is_empty() {
+        return Ok(first);
+    }
+    others.push(first);
+    others.sort_by_key(Prefecture::jis_x_0401_code);
+    Err(Error::Ambiguous {
+        name: name.to_string(),
+        candidates: others,
+    })
+}
+
+/// Minimum score for a candidate to be returned by `search_fuzzy`
+const FUZZY_THRESHOLD: f32 = 0.5;
+
+/// Normalized similarity of two strings based on their edit distance
+fn similarity(a: &str, b: &str) -> f32 {
+    let len = a.chars().count().max(b.chars().count());
+    if len == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f32 / len as f32
+}
+
+/// Levenshtein distance counted in chars
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let cost = usize::from(a_char != *b_char);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// Find a prefecture by name, tolerating casual kana spellings
+///
+/// Accepts the same name forms as [`find`]. When no exact match is found, kana input is
+/// compared after folding katakana to hiragana, small kana to full size, and dropping
+/// long-vowel marks and the う/お that lengthen an o-row vowel. A folded name matching
+/// more than one prefecture is rejected with [`Error::Ambiguous`].
+///
+/// Inputs longer than [`DEFAULT_MAX_INPUT_LEN`] bytes are rejected with
+/// [`Error::InputTooLong`]; use [`find_lenient_with_max_len`] to choose another limit.
 ///
 /// # Examples
 ///
 /// ```
 /// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
 ///
-/// assert_eq!(prefectures::find_by_english("tokyo"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find_by_english("Tokyo"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find_by_english("tOkYo"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find_by_english("tokyo~~~"), Err(Error::InvalidPrefectureName("tokyo~~~".to_string())));
+/// assert_eq!(prefectures::find_lenient("トーキョー"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_lenient("オーサカ"), Ok(Prefecture::Osaka));
+/// assert_eq!(prefectures::find_lenient("ほっかいどー"), Ok(Prefecture::Hokkaido));
+/// assert_eq!(prefectures::find_lenient("とうきよう"), Ok(Prefecture::Tokyo));
+/// assert_eq!(prefectures::find_lenient("none"), Err(Error::InvalidPrefectureName { name: "none".to_string() }));
 /// ```
-pub fn find_by_english<T: AsRef<str> + ToString>(english: T) -> Result<Prefecture, Error> {
-    PREFECTURE_MAP
-        .iter()
-        .find(|(_, data)| data.english == english.as_ref().to_lowercase())
-        .map(|(pref, _)| *pref)
-        .ok_or_else(|| Error::InvalidPrefectureName(english.to_string()))
+#[cfg(feature = "kana")]
+pub fn find_lenient<T: AsRef<str>>(s: T) -> Result<Prefecture, Error> {
+    find_lenient_with_max_len(s, DEFAULT_MAX_INPUT_LEN)
 }
 
-/// Find a prefecture by name
+/// Find a prefecture by name, tolerating casual kana spellings, accepting inputs of up to
+/// `max_len` bytes
+///
+/// Behaves like [`find_lenient`] otherwise.
 ///
 /// # Examples
 ///
 /// ```
 /// use jp_prefecture::{prefectures::{self, Prefecture}, Error};
 ///
-/// assert_eq!(prefectures::find("東京都"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find("東京"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find("とうきょうと"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find("とうきょう"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find("トウキョウト"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find("トウキョウ"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find("tokyo"), Ok(Prefecture::Tokyo));
-/// assert_eq!(prefectures::find("none"), Err(Error::InvalidPrefectureName("none".to_string())));
+/// assert_eq!(prefectures::find_lenient_with_max_len("トーキョー", 15), Ok(Prefecture::Tokyo));
+/// assert_eq!(
+///     prefectures::find_lenient_with_max_len("トーキョー", 14),
+///     Err(Error::InputTooLong { len: 15, max: 14 })
+/// );
 /// ```
-pub fn find<T: AsRef<str>>(s: T) -> Result<Prefecture, Error> {
-    Prefecture::from_str(s.as_ref())
+#[cfg(feature = "kana")]
+pub fn find_lenient_with_max_len<T: AsRef<str>>(s: T, max_len: usize) -> Result<Prefecture, Error> {
+    let s = s.as_ref();
+    check_len(s, max_len)?;
+    if let Ok(pref) = find(s) {
+        return Ok(pref);
+    }
+    let folded = fold_kana(s);
+    let matches = Prefecture::VARIANTS.iter().copied().filter(|pref| {
+        let names = pref.names();
+        fold_kana(names.hiragana) == folded || fold_kana(names.hiragana_short) == folded
+    });
+    unique_match(s, matches)
+}
+
+/// Fold kana spelling variants so that e.g. "トーキョー" and "とうきょう" compare equal
+#[cfg(feature = "kana")]
+fn fold_kana(kana: &str) -> String {
+    fold_kana_chars(kana).collect()
+}
+
+/// Fold kana spelling variants char by char
+#[cfg(feature = "kana")]
+pub(crate) fn fold_kana_chars(kana: &str) -> impl Iterator<Item = char> + '_ {
+    let mut after_o_row = false;
+    kana.chars()
+        .map(|c| match c {
+            'ァ'..='ヶ' => char::from_u32(c as u32 - 0x60).expect("Unexpected error"),
+            _ => c,
+        })
+        .map(|c| match c {
+            'ぁ' => 'あ',
+            'ぃ' => 'い',
+            'ぅ' => 'う',
+            'ぇ' => 'え',
+            'ぉ' => 'お',
+            'っ' => 'つ',
+            'ゃ' => 'や',
+            'ゅ' => 'ゆ',
+            'ょ' => 'よ',
+            'ゎ' => 'わ',
+            _ => c,
+        })
+        .filter(move |&c| {
+            if c == 'ー' || (after_o_row && (c == 'う' || c == 'お')) {
+                return false;
+            }
+            after_o_row = "おこごそぞとどのほぼぽもよろを".contains(c);
+            true
+        })
 }
 
 impl FromStr for Prefecture {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut map: HashMap<String, Prefecture> = HashMap::new();
-        PREFECTURE_MAP.iter().for_each(|(pref, _)| {
-            map.insert(pref.kanji(), *pref);
-            map.insert(pref.kanji_short(), *pref);
-            map.insert(pref.hiragana(), *pref);
-            map.insert(pref.hiragana_short(), *pref);
-            map.insert(pref.katakana(), *pref);
-            map.insert(pref.katakana_short(), *pref);
-            map.insert(pref.english().to_lowercase(), *pref);
-        });
-        map.get(s.to_ascii_lowercase().as_str())
+        NAME_MAP
+            .get(s.to_ascii_lowercase().as_str())
             .copied()
-            .ok_or_else(|| Self::Err::InvalidPrefectureName(s.to_string()))
+            .ok_or_else(|| Self::Err::InvalidPrefectureName {
+                name: s.to_string(),
+            })
     }
 }
 
@@ -544,6 +1761,7 @@ mod tests {
         prefecture.kanji_short()
     }
 
+    #[cfg(feature = "kana")]
     #[test_case(Prefecture::Hokkaido => String::from("ほっかいどう"))]
     #[test_case(Prefecture::Aomori => String::from("あおもりけん"))]
     #[test_case(Prefecture::Iwate => String::from("いわてけん"))]
@@ -595,6 +1813,7 @@ mod tests {
         prefecture.hiragana()
     }
 
+    #[cfg(feature = "kana")]
     #[test_case(Prefecture::Hokkaido => String::from("ほっかいどう"))]
     #[test_case(Prefecture::Aomori => String::from("あおもり"))]
     #[test_case(Prefecture::Iwate => String::from("いわて"))]
@@ -646,6 +1865,7 @@ mod tests {
         prefecture.hiragana_short()
     }
 
+    #[cfg(feature = "kana")]
     #[test_case(Prefecture::Hokkaido => String::from("ホッカイドウ"))]
     #[test_case(Prefecture::Aomori => String::from("アオモリケン"))]
     #[test_case(Prefecture::Iwate => String::from("イワテケン"))]
@@ -697,6 +1917,7 @@ mod tests {
         prefecture.katakana()
     }
 
+    #[cfg(feature = "kana")]
     #[test_case(Prefecture::Hokkaido => String::from("ホッカイドウ"))]
     #[test_case(Prefecture::Aomori => String::from("アオモリ"))]
     #[test_case(Prefecture::Iwate => String::from("イワテ"))]
@@ -748,6 +1969,7 @@ mod tests {
         prefecture.katakana_short()
     }
 
+    #[cfg(feature = "english")]
     #[test_case(Prefecture::Hokkaido => String::from("Hokkaido"))]
     #[test_case(Prefecture::Aomori => String::from("Aomori"))]
     #[test_case(Prefecture::Iwate => String::from("Iwate"))]
@@ -799,6 +2021,29 @@ mod tests {
         prefecture.english()
     }
 
+    #[cfg(feature = "english")]
+    #[test]
+    fn slug_tests() {
+        for pref in Prefecture::VARIANTS {
+            let slug = pref.slug();
+            assert!(slug.bytes().all(|b| b.is_ascii_lowercase()), "{}", slug);
+            assert_eq!(find_by_slug(&slug), Ok(pref));
+        }
+    }
+
+    #[cfg(feature = "english")]
+    #[test_case("tokyo" => Ok(Prefecture::Tokyo))]
+    #[test_case("hokkaido" => Ok(Prefecture::Hokkaido))]
+    #[test_case("Tokyo" => Err(Error::InvalidPrefectureName { name: "Tokyo".to_string() }))]
+    #[test_case(" tokyo" => Err(Error::InvalidPrefectureName { name: " tokyo".to_string() }))]
+    #[test_case("tokyo-to" => Err(Error::InvalidPrefectureName { name: "tokyo-to".to_string() }))]
+    #[test_case("toukyou" => Err(Error::InvalidPrefectureName { name: "toukyou".to_string() }))]
+    #[test_case("東京" => Err(Error::InvalidPrefectureName { name: "東京".to_string() }); "kanji")]
+    #[test_case("" => Err(Error::InvalidPrefectureName { name: "".to_string() }))]
+    fn find_by_slug_tests(slug: &str) -> Result<Prefecture, Error> {
+        find_by_slug(slug)
+    }
+
     #[test_case(1 => Ok(Prefecture::Hokkaido))]
     #[test_case(2 => Ok(Prefecture::Aomori))]
     #[test_case(3 => Ok(Prefecture::Iwate))]
@@ -846,11 +2091,33 @@ mod tests {
     #[test_case(45 => Ok(Prefecture::Miyazaki))]
     #[test_case(46 => Ok(Prefecture::Kagoshima))]
     #[test_case(47 => Ok(Prefecture::Okinawa))]
-    #[test_case(48 => Err(Error::InvalidPrefectureCode(48)))]
+    #[test_case(48 => Err(Error::code_out_of_range(48)))]
     fn find_by_code_tests(code: u32) -> Result<Prefecture, Error> {
         find_by_code(code)
     }
 
+    #[test_case("1" => Ok(Prefecture::Hokkaido))]
+    #[test_case("01" => Ok(Prefecture::Hokkaido))]
+    #[test_case("001" => Ok(Prefecture::Hokkaido))]
+    #[test_case("13" => Ok(Prefecture::Tokyo))]
+    #[test_case(" 13 " => Ok(Prefecture::Tokyo); "tokyo with spaces")]
+    #[test_case("１３" => Ok(Prefecture::Tokyo); "tokyo in full width")]
+    #[test_case("０１" => Ok(Prefecture::Hokkaido); "hokkaido in full width")]
+    #[test_case("4７" => Ok(Prefecture::Okinawa); "okinawa in mixed width")]
+    #[test_case("0" => Err(Error::code_out_of_range(0)))]
+    #[test_case("48" => Err(Error::code_out_of_range(48)))]
+    #[test_case("" => Err(Error::MalformedPrefectureCode { input: "".to_string() }))]
+    #[test_case("-1" => Err(Error::MalformedPrefectureCode { input: "-1".to_string() }))]
+    #[test_case("1.0" => Err(Error::MalformedPrefectureCode { input: "1.0".to_string() }))]
+    #[test_case("東京" => Err(Error::MalformedPrefectureCode { input: "東京".to_string() }); "kanji")]
+    #[test_case("99999999999" => Err(Error::code_out_of_range(99999999999)))]
+    #[test_case("000000000000000000000000013" => Ok(Prefecture::Tokyo) ; "long zero padding")]
+    #[test_case("99999999999999999999999" => Err(Error::MalformedPrefectureCode { input: "99999999999999999999999".to_string() }) ; "over u64")]
+    #[test_case("４８" => Err(Error::code_out_of_range(48)) ; "full width out of range")]
+    fn find_by_code_str_tests(code: &str) -> Result<Prefecture, Error> {
+        find_by_code_str(code)
+    }
+
     #[test_case("北海道" => Ok(Prefecture::Hokkaido))]
     #[test_case("青森県" => Ok(Prefecture::Aomori))]
     #[test_case("青森" => Ok(Prefecture::Aomori))]
@@ -944,11 +2211,12 @@ mod tests {
     #[test_case("鹿児島" => Ok(Prefecture::Kagoshima))]
     #[test_case("沖縄県" => Ok(Prefecture::Okinawa))]
     #[test_case("沖縄" => Ok(Prefecture::Okinawa))]
-    #[test_case("None" => Err(Error::InvalidPrefectureName("None".to_string())))]
+    #[test_case("None" => Err(Error::InvalidPrefectureName { name: "None".to_string() }))]
     fn find_by_kanji_tests(kanji: &str) -> Result<Prefecture, Error> {
         find_by_kanji(kanji)
     }
 
+    #[cfg(feature = "kana")]
     #[test_case("ほっかいどう" => Ok(Prefecture::Hokkaido))]
     #[test_case("あおもりけん" => Ok(Prefecture::Aomori))]
     #[test_case("あおもり" => Ok(Prefecture::Aomori))]
@@ -1042,11 +2310,12 @@ mod tests {
     #[test_case("かごしま" => Ok(Prefecture::Kagoshima))]
     #[test_case("おきなわけん" => Ok(Prefecture::Okinawa))]
     #[test_case("おきなわ" => Ok(Prefecture::Okinawa))]
-    #[test_case("None" => Err(Error::InvalidPrefectureName("None".to_string())))]
+    #[test_case("None" => Err(Error::InvalidPrefectureName { name: "None".to_string() }))]
     fn find_by_hiragana_tests(hiragana: &str) -> Result<Prefecture, Error> {
         find_by_hiragana(hiragana)
     }
 
+    #[cfg(feature = "kana")]
     #[test_case("ホッカイドウ" => Ok(Prefecture::Hokkaido))]
     #[test_case("アオモリケン" => Ok(Prefecture::Aomori))]
     #[test_case("アオモリ" => Ok(Prefecture::Aomori))]
@@ -1140,11 +2409,12 @@ mod tests {
     #[test_case("カゴシマ" => Ok(Prefecture::Kagoshima))]
     #[test_case("オキナワケン" => Ok(Prefecture::Okinawa))]
     #[test_case("オキナワ" => Ok(Prefecture::Okinawa))]
-    #[test_case("None" => Err(Error::InvalidPrefectureName("None".to_string())))]
+    #[test_case("None" => Err(Error::InvalidPrefectureName { name: "None".to_string() }))]
     fn find_by_katakana_tests(katakana: &str) -> Result<Prefecture, Error> {
         find_by_katakana(katakana)
     }
 
+    #[cfg(feature = "english")]
     #[test_case("hokkaido" => Ok(Prefecture::Hokkaido))]
     #[test_case("aomori" => Ok(Prefecture::Aomori))]
     #[test_case("iwate" => Ok(Prefecture::Iwate))]
@@ -1192,11 +2462,12 @@ mod tests {
     #[test_case("miyazaki" => Ok(Prefecture::Miyazaki))]
     #[test_case("kagoshima" => Ok(Prefecture::Kagoshima))]
     #[test_case("okinawa" => Ok(Prefecture::Okinawa))]
-    #[test_case("None" => Err(Error::InvalidPrefectureName("None".to_string())))]
+    #[test_case("None" => Err(Error::InvalidPrefectureName { name: "None".to_string() }))]
     fn find_by_english_tests_from_lower_case(english: &str) -> Result<Prefecture, Error> {
         find_by_english(english)
     }
 
+    #[cfg(feature = "english")]
     #[test_case("Hokkaido" => Ok(Prefecture::Hokkaido))]
     #[test_case("Aomori" => Ok(Prefecture::Aomori))]
     #[test_case("Iwate" => Ok(Prefecture::Iwate))]
@@ -1244,11 +2515,172 @@ mod tests {
     #[test_case("Miyazaki" => Ok(Prefecture::Miyazaki))]
     #[test_case("Kagoshima" => Ok(Prefecture::Kagoshima))]
     #[test_case("Okinawa" => Ok(Prefecture::Okinawa))]
-    #[test_case("None" => Err(Error::InvalidPrefectureName("None".to_string())))]
+    #[test_case("None" => Err(Error::InvalidPrefectureName { name: "None".to_string() }))]
     fn find_by_english_tests_from_upper_case(english: &str) -> Result<Prefecture, Error> {
         find_by_english(english)
     }
 
+    #[cfg(feature = "english")]
+    #[test_case(Prefecture::Hokkaido => String::from("Hokkaidô"))]
+    #[test_case(Prefecture::Aomori => String::from("Aomori"))]
+    #[test_case(Prefecture::Iwate => String::from("Iwate"))]
+    #[test_case(Prefecture::Miyagi => String::from("Miyagi"))]
+    #[test_case(Prefecture::Akita => String::from("Akita"))]
+    #[test_case(Prefecture::Yamagata => String::from("Yamagata"))]
+    #[test_case(Prefecture::Fukushima => String::from("Hukusima"))]
+    #[test_case(Prefecture::Ibaraki => String::from("Ibaraki"))]
+    #[test_case(Prefecture::Tochigi => String::from("Totigi"))]
+    #[test_case(Prefecture::Gunma => String::from("Gunma"))]
+    #[test_case(Prefecture::Saitama => String::from("Saitama"))]
+    #[test_case(Prefecture::Chiba => String::from("Tiba"))]
+    #[test_case(Prefecture::Tokyo => String::from("Tôkyô"))]
+    #[test_case(Prefecture::Kanagawa => String::from("Kanagawa"))]
+    #[test_case(Prefecture::Niigata => String::from("Niigata"))]
+    #[test_case(Prefecture::Toyama => String::from("Toyama"))]
+    #[test_case(Prefecture::Ishikawa => String::from("Isikawa"))]
+    #[test_case(Prefecture::Fukui => String::from("Hukui"))]
+    #[test_case(Prefecture::Yamanashi => String::from("Yamanasi"))]
+    #[test_case(Prefecture::Nagano => String::from("Nagano"))]
+    #[test_case(Prefecture::Gifu => String::from("Gihu"))]
+    #[test_case(Prefecture::Shizuoka => String::from("Sizuoka"))]
+    #[test_case(Prefecture::Aichi => String::from("Aiti"))]
+    #[test_case(Prefecture::Mie => String::from("Mie"))]
+    #[test_case(Prefecture::Shiga => String::from("Siga"))]
+    #[test_case(Prefecture::Kyoto => String::from("Kyôto"))]
+    #[test_case(Prefecture::Osaka => String::from("Ôsaka") ; "osaka")]
+    #[test_case(Prefecture::Hyogo => String::from("Hyôgo"))]
+    #[test_case(Prefecture::Nara => String::from("Nara"))]
+    #[test_case(Prefecture::Wakayama => String::from("Wakayama"))]
+    #[test_case(Prefecture::Tottori => String::from("Tottori"))]
+    #[test_case(Prefecture::Shimane => String::from("Simane"))]
+    #[test_case(Prefecture::Okayama => String::from("Okayama"))]
+    #[test_case(Prefecture::Hiroshima => String::from("Hirosima"))]
+    #[test_case(Prefecture::Yamaguchi => String::from("Yamaguti"))]
+    #[test_case(Prefecture::Tokushima => String::from("Tokusima"))]
+    #[test_case(Prefecture::Kagawa => String::from("Kagawa"))]
+    #[test_case(Prefecture::Ehime => String::from("Ehime"))]
+    #[test_case(Prefecture::Kochi => String::from("Kôti"))]
+    #[test_case(Prefecture::Fukuoka => String::from("Hukuoka"))]
+    #[test_case(Prefecture::Saga => String::from("Saga"))]
+    #[test_case(Prefecture::Nagasaki => String::from("Nagasaki"))]
+    #[test_case(Prefecture::Kumamoto => String::from("Kumamoto"))]
+    #[test_case(Prefecture::Oita => String::from("Ôita") ; "oita")]
+    #[test_case(Prefecture::Miyazaki => String::from("Miyazaki"))]
+    #[test_case(Prefecture::Kagoshima => String::from("Kagosima"))]
+    #[test_case(Prefecture::Okinawa => String::from("Okinawa"))]
+    fn kunrei_tests(prefecture: Prefecture) -> String {
+        prefecture.kunrei()
+    }
+
+    #[cfg(feature = "english")]
+    #[test_case("Hokkaidô" => Ok(Prefecture::Hokkaido))]
+    #[test_case("Aomori" => Ok(Prefecture::Aomori))]
+    #[test_case("Iwate" => Ok(Prefecture::Iwate))]
+    #[test_case("Miyagi" => Ok(Prefecture::Miyagi))]
+    #[test_case("Akita" => Ok(Prefecture::Akita))]
+    #[test_case("Yamagata" => Ok(Prefecture::Yamagata))]
+    #[test_case("Hukusima" => Ok(Prefecture::Fukushima))]
+    #[test_case("Ibaraki" => Ok(Prefecture::Ibaraki))]
+    #[test_case("Totigi" => Ok(Prefecture::Tochigi))]
+    #[test_case("Gunma" => Ok(Prefecture::Gunma))]
+    #[test_case("Saitama" => Ok(Prefecture::Saitama))]
+    #[test_case("Tiba" => Ok(Prefecture::Chiba))]
+    #[test_case("Tôkyô" => Ok(Prefecture::Tokyo))]
+    #[test_case("Kanagawa" => Ok(Prefecture::Kanagawa))]
+    #[test_case("Niigata" => Ok(Prefecture::Niigata))]
+    #[test_case("Toyama" => Ok(Prefecture::Toyama))]
+    #[test_case("Isikawa" => Ok(Prefecture::Ishikawa))]
+    #[test_case("Hukui" => Ok(Prefecture::Fukui))]
+    #[test_case("Yamanasi" => Ok(Prefecture::Yamanashi))]
+    #[test_case("Nagano" => Ok(Prefecture::Nagano))]
+    #[test_case("Gihu" => Ok(Prefecture::Gifu))]
+    #[test_case("Sizuoka" => Ok(Prefecture::Shizuoka))]
+    #[test_case("Aiti" => Ok(Prefecture::Aichi))]
+    #[test_case("Mie" => Ok(Prefecture::Mie))]
+    #[test_case("Siga" => Ok(Prefecture::Shiga))]
+    #[test_case("Kyôto" => Ok(Prefecture::Kyoto))]
+    #[test_case("Ôsaka" => Ok(Prefecture::Osaka) ; "osaka")]
+    #[test_case("Hyôgo" => Ok(Prefecture::Hyogo))]
+    #[test_case("Nara" => Ok(Prefecture::Nara))]
+    #[test_case("Wakayama" => Ok(Prefecture::Wakayama))]
+    #[test_case("Tottori" => Ok(Prefecture::Tottori))]
+    #[test_case("Simane" => Ok(Prefecture::Shimane))]
+    #[test_case("Okayama" => Ok(Prefecture::Okayama))]
+    #[test_case("Hirosima" => Ok(Prefecture::Hiroshima))]
+    #[test_case("Yamaguti" => Ok(Prefecture::Yamaguchi))]
+    #[test_case("Tokusima" => Ok(Prefecture::Tokushima))]
+    #[test_case("Kagawa" => Ok(Prefecture::Kagawa))]
+    #[test_case("Ehime" => Ok(Prefecture::Ehime))]
+    #[test_case("Kôti" => Ok(Prefecture::Kochi))]
+    #[test_case("Hukuoka" => Ok(Prefecture::Fukuoka))]
+    #[test_case("Saga" => Ok(Prefecture::Saga))]
+    #[test_case("Nagasaki" => Ok(Prefecture::Nagasaki))]
+    #[test_case("Kumamoto" => Ok(Prefecture::Kumamoto))]
+    #[test_case("Ôita" => Ok(Prefecture::Oita) ; "oita")]
+    #[test_case("Miyazaki" => Ok(Prefecture::Miyazaki))]
+    #[test_case("Kagosima" => Ok(Prefecture::Kagoshima))]
+    #[test_case("Okinawa" => Ok(Prefecture::Okinawa))]
+    #[test_case("SIZUOKA" => Ok(Prefecture::Shizuoka))]
+    #[test_case("ÔSAKA" => Ok(Prefecture::Osaka) ; "osaka uppercase")]
+    #[test_case("Koti" => Ok(Prefecture::Kochi))]
+    #[test_case("Hokkaido" => Ok(Prefecture::Hokkaido))]
+    #[test_case("Tôkyo" => Ok(Prefecture::Tokyo))]
+    #[test_case("Sizuoka~" => Err(Error::InvalidPrefectureName { name: "Sizuoka~".to_string() }))]
+    fn find_by_english_tests_from_kunrei(kunrei: &str) -> Result<Prefecture, Error> {
+        find_by_english(kunrei)
+    }
+
+    #[cfg(feature = "english")]
+    #[test_case("Gumma" => Ok(Prefecture::Gunma))]
+    #[test_case("Oosaka" => Ok(Prefecture::Osaka))]
+    #[test_case("Ohsaka" => Ok(Prefecture::Osaka))]
+    #[test_case("Ousaka" => Ok(Prefecture::Osaka))]
+    #[test_case("Tookyoo" => Ok(Prefecture::Tokyo))]
+    #[test_case("Tohkyoh" => Ok(Prefecture::Tokyo))]
+    #[test_case("Toukyou" => Ok(Prefecture::Tokyo))]
+    #[test_case("Kouchi" => Ok(Prefecture::Kochi))]
+    #[test_case("Kohchi" => Ok(Prefecture::Kochi))]
+    #[test_case("Ooita" => Ok(Prefecture::Oita))]
+    #[test_case("Ohita" => Ok(Prefecture::Oita))]
+    #[test_case("Hyougo" => Ok(Prefecture::Hyogo))]
+    #[test_case("Hyohgo" => Ok(Prefecture::Hyogo))]
+    #[test_case("Hokkaidou" => Ok(Prefecture::Hokkaido))]
+    #[test_case("Kyouto" => Ok(Prefecture::Kyoto))]
+    #[test_case("Tōkyō" => Ok(Prefecture::Tokyo) ; "tokyo with macrons")]
+    #[test_case("Ōsaka" => Ok(Prefecture::Osaka) ; "osaka with macron")]
+    #[test_case("Gummma" => Err(Error::InvalidPrefectureName { name: "Gummma".to_string() }))]
+    #[test_case("Toooouuuhhhkyo" => Err(Error::InvalidPrefectureName { name: "Toooouuuhhhkyo".to_string() }))]
+    #[test_case("Tohuhokyo" => Err(Error::InvalidPrefectureName { name: "Tohuhokyo".to_string() }))]
+    #[test_case("Tooukyo" => Err(Error::InvalidPrefectureName { name: "Tooukyo".to_string() }))]
+    #[test_case("Ooosaka" => Err(Error::InvalidPrefectureName { name: "Ooosaka".to_string() }))]
+    #[test_case("Kohhchi" => Err(Error::InvalidPrefectureName { name: "Kohhchi".to_string() }))]
+    fn find_by_english_tests_from_alternative_spelling(english: &str) -> Result<Prefecture, Error> {
+        find_by_english(english)
+    }
+
+    #[cfg(feature = "english")]
+    #[test]
+    fn fold_romaji_is_unambiguous() {
+        let mut seen = HashMap::new();
+        for pref in Prefecture::VARIANTS {
+            let data = pref.data();
+            for name in [data.english, data.kunrei] {
+                if let Some(other) = seen.insert(fold_romaji(name), pref) {
+                    assert_eq!(other, pref, "{} is ambiguous", name);
+                }
+            }
+        }
+    }
+
+    #[test_case(vec![] => Err(Error::InvalidPrefectureName { name: "name".to_string() }) ; "no match")]
+    #[test_case(vec![Prefecture::Tokyo] => Ok(Prefecture::Tokyo) ; "one match")]
+    #[test_case(vec![Prefecture::Tokyo, Prefecture::Tokyo] => Ok(Prefecture::Tokyo) ; "same match twice")]
+    #[test_case(vec![Prefecture::Kyoto, Prefecture::Tokyo, Prefecture::Kyoto] => Err(Error::Ambiguous { name: "name".to_string(), candidates: vec![Prefecture::Tokyo, Prefecture::Kyoto] }) ; "two matches")]
+    fn unique_match_tests(matches: Vec<Prefecture>) -> Result<Prefecture, Error> {
+        unique_match("name", matches)
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
     #[test_case("東京都" => Ok(Prefecture::Tokyo))]
     #[test_case("東京" => Ok(Prefecture::Tokyo))]
     #[test_case("とうきょうと" => Ok(Prefecture::Tokyo))]
@@ -1257,11 +2689,12 @@ mod tests {
     #[test_case("トウキョウ" => Ok(Prefecture::Tokyo))]
     #[test_case("tokyo" => Ok(Prefecture::Tokyo))]
     #[test_case("HoKkaido" => Ok(Prefecture::Hokkaido))]
-    #[test_case("none" => Err(Error::InvalidPrefectureName("none".to_string())))]
+    #[test_case("none" => Err(Error::InvalidPrefectureName { name: "none".to_string() }))]
     fn find_tests(s: &str) -> Result<Prefecture, Error> {
         find(s)
     }
 
+    #[cfg(all(feature = "kana", feature = "english"))]
     #[test_case("東京都" => Ok(Prefecture::Tokyo))]
     #[test_case("東京" => Ok(Prefecture::Tokyo))]
     #[test_case("とうきょうと" => Ok(Prefecture::Tokyo))]
@@ -1270,8 +2703,566 @@ mod tests {
     #[test_case("トウキョウ" => Ok(Prefecture::Tokyo))]
     #[test_case("tokyo" => Ok(Prefecture::Tokyo))]
     #[test_case("HoKkaido" => Ok(Prefecture::Hokkaido))]
-    #[test_case("error" => Err(Error::InvalidPrefectureName("error".to_string())))]
+    #[test_case("error" => Err(Error::InvalidPrefectureName { name: "error".to_string() }))]
     fn from_str_tests(s: &str) -> Result<Prefecture, Error> {
         Prefecture::from_str(s)
     }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test_case("東京都" => Ok(Prefecture::Tokyo))]
+    #[test_case("東京" => Ok(Prefecture::Tokyo))]
+    #[test_case("とうきょう" => Ok(Prefecture::Tokyo))]
+    #[test_case("トウキョウト" => Ok(Prefecture::Tokyo))]
+    #[test_case("tokyo" => Ok(Prefecture::Tokyo))]
+    #[test_case("HoKkaido" => Ok(Prefecture::Hokkaido))]
+    #[test_case("東京県" => Err(ParseError::InvalidPrefectureName { name: "東京県" }))]
+    #[test_case("" => Err(ParseError::InvalidPrefectureName { name: "" }))]
+    fn parse_tests(s: &str) -> Result<Prefecture, ParseError<'_>> {
+        parse(s)
+    }
+
+    #[test]
+    fn parse_agrees_with_find() {
+        for pref in Prefecture::VARIANTS {
+            for name in pref.names().forms() {
+                let upper = name.to_ascii_uppercase();
+                assert_eq!(parse(name).map_err(Error::from), find(name));
+                assert_eq!(parse(&upper).map_err(Error::from), find(&upper));
+            }
+        }
+        assert_eq!(parse("none").map_err(Error::from), find("none"));
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test_case("山" => vec![Prefecture::Yamagata, Prefecture::Yamanashi, Prefecture::Yamaguchi])]
+    #[test_case("やま" => vec![Prefecture::Yamagata, Prefecture::Yamanashi, Prefecture::Yamaguchi])]
+    #[test_case("ヤマ" => vec![Prefecture::Yamagata, Prefecture::Yamanashi, Prefecture::Yamaguchi])]
+    #[test_case("yama" => vec![Prefecture::Yamagata, Prefecture::Yamanashi, Prefecture::Yamaguchi])]
+    #[test_case("YAMA" => vec![Prefecture::Yamagata, Prefecture::Yamanashi, Prefecture::Yamaguchi])]
+    #[test_case("東京都" => vec![Prefecture::Tokyo])]
+    #[test_case("東京都庁" => Vec::<Prefecture>::new() ; "longer than name")]
+    #[test_case("TOKYO" => vec![Prefecture::Tokyo])]
+    #[test_case("ほっかいどう" => vec![Prefecture::Hokkaido])]
+    #[test_case("" => Vec::<Prefecture>::new())]
+    #[test_case("none" => Vec::<Prefecture>::new())]
+    fn search_prefix_tests(prefix: &str) -> Vec<Prefecture> {
+        search_prefix(prefix)
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test_case("tokyo" => Some(Prefecture::Tokyo))]
+    #[test_case("tokio" => Some(Prefecture::Tokyo))]
+    #[test_case("Hokaido" => Some(Prefecture::Hokkaido))]
+    #[test_case("kanagwa" => Some(Prefecture::Kanagawa))]
+    #[test_case("とうきよう" => Some(Prefecture::Tokyo))]
+    #[test_case("オオサカフ" => Some(Prefecture::Osaka))]
+    #[test_case("神奈川" => Some(Prefecture::Kanagawa))]
+    #[test_case("xxxxxxxx" => None)]
+    #[test_case("" => None)]
+    fn search_fuzzy_best_match_tests(query: &str) -> Option<Prefecture> {
+        search_fuzzy(query).unwrap().first().map(|(pref, _)| *pref)
+    }
+
+    #[cfg(feature = "english")]
+    #[test]
+    fn search_fuzzy_is_ranked_tests() {
+        let candidates = search_fuzzy("yamag").unwrap();
+        assert!(candidates.len() > 1);
+        assert!(candidates.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(candidates
+            .iter()
+            .all(|(_, score)| (FUZZY_THRESHOLD..=1.0).contains(score)));
+    }
+
+    #[test_case("あ".repeat(85), DEFAULT_MAX_INPUT_LEN => Ok(()) ; "at default limit")]
+    #[test_case("あ".repeat(86), DEFAULT_MAX_INPUT_LEN => Err(Error::InputTooLong { len: 258, max: 256 }) ; "over default limit")]
+    #[test_case("tokyo".to_string(), 0 => Err(Error::InputTooLong { len: 5, max: 0 }) ; "zero limit")]
+    fn search_fuzzy_length_tests(query: String, max_len: usize) -> Result<(), Error> {
+        search_fuzzy_with_max_len(query, max_len).map(|_| ())
+    }
+
+    #[test_case("kitten", "sitting" => 3)]
+    #[test_case("とうきょう", "ときょう" => 1)]
+    #[test_case("", "abc" => 3)]
+    #[test_case("abc", "abc" => 0)]
+    fn levenshtein_tests(a: &str, b: &str) -> usize {
+        levenshtein(a, b)
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test]
+    fn find_all_tests() {
+        let names = vec![
+            "北海道".to_string(),
+            "Tokyo".to_string(),
+            "error".to_string(),
+        ];
+        assert_eq!(
+            find_all(&names),
+            vec![
+                Ok(Prefecture::Hokkaido),
+                Ok(Prefecture::Tokyo),
+                Err(Error::InvalidPrefectureName {
+                    name: "error".to_string()
+                }),
+            ]
+        );
+        assert_eq!(find_all(Vec::<&str>::new()), vec![]);
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test_case("北海道", "沖縄県" => cmp::Ordering::Less)]
+    #[test_case("沖縄", "Hokkaido" => cmp::Ordering::Greater)]
+    #[test_case("とうきょう", "東京都" => cmp::Ordering::Equal)]
+    #[test_case("東京県", "沖縄県" => cmp::Ordering::Greater)]
+    #[test_case("沖縄県", "東京県" => cmp::Ordering::Less)]
+    #[test_case("東京県", "none" => cmp::Ordering::Equal)]
+    fn cmp_by_code_tests(a: &str, b: &str) -> cmp::Ordering {
+        cmp_by_code(a, b)
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test]
+    fn sort_names_tests() {
+        let mut names = vec![
+            "沖縄県".to_string(),
+            "b".to_string(),
+            "Kyoto".to_string(),
+            "a".to_string(),
+            "あおもり".to_string(),
+            "北海道".to_string(),
+        ];
+        sort_names(&mut names);
+        assert_eq!(
+            names,
+            vec!["北海道", "あおもり", "Kyoto", "沖縄県", "b", "a"]
+        );
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test_case("トーキョー" => Ok(Prefecture::Tokyo); "tokyo with long vowel marks")]
+    #[test_case("とーきょーと" => Ok(Prefecture::Tokyo); "tokyo in hiragana with suffix")]
+    #[test_case("とうきよう" => Ok(Prefecture::Tokyo); "tokyo without small kana")]
+    #[test_case("オーサカ" => Ok(Prefecture::Osaka); "osaka with long vowel mark")]
+    #[test_case("おうさかふ" => Ok(Prefecture::Osaka); "osaka with u")]
+    #[test_case("ほっかいどー" => Ok(Prefecture::Hokkaido); "hokkaido with long vowel mark")]
+    #[test_case("ホッカイドー" => Ok(Prefecture::Hokkaido); "hokkaido in katakana")]
+    #[test_case("コーチ" => Ok(Prefecture::Kochi); "kochi")]
+    #[test_case("オーイタ" => Ok(Prefecture::Oita); "oita")]
+    #[test_case("ヒョーゴ" => Ok(Prefecture::Hyogo); "hyogo")]
+    #[test_case("東京" => Ok(Prefecture::Tokyo); "exact kanji")]
+    #[test_case("tokyo" => Ok(Prefecture::Tokyo))]
+    #[test_case("とーきょーけん" => Err(Error::InvalidPrefectureName { name: "とーきょーけん".to_string() }); "wrong suffix")]
+    #[test_case("ー" => Err(Error::InvalidPrefectureName { name: "ー".to_string() }); "long vowel mark only")]
+    #[test_case("" => Err(Error::InvalidPrefectureName { name: "".to_string() }))]
+    fn find_lenient_tests(s: &str) -> Result<Prefecture, Error> {
+        find_lenient(s)
+    }
+
+    #[cfg(feature = "kana")]
+    #[test]
+    fn find_lenient_rejects_long_input() {
+        assert_eq!(
+            find_lenient("ー".repeat(DEFAULT_MAX_INPUT_LEN)),
+            Err(Error::InputTooLong {
+                len: DEFAULT_MAX_INPUT_LEN * 3,
+                max: DEFAULT_MAX_INPUT_LEN
+            })
+        );
+    }
+
+    #[cfg(feature = "kana")]
+    #[test_case("あ".repeat(85), DEFAULT_MAX_INPUT_LEN => Err(Error::InvalidPrefectureName { name: "あ".repeat(85) }) ; "at default limit")]
+    #[test_case("あ".repeat(86), DEFAULT_MAX_INPUT_LEN => Err(Error::InputTooLong { len: 258, max: 256 }) ; "over default limit")]
+    #[test_case("ー".repeat(400), 1200 => Err(Error::InvalidPrefectureName { name: "ー".repeat(400) }) ; "raised limit")]
+    #[test_case("トーキョー".to_string(), 15 => Ok(Prefecture::Tokyo) ; "at custom limit")]
+    #[test_case("トーキョー".to_string(), 14 => Err(Error::InputTooLong { len: 15, max: 14 }) ; "over custom limit")]
+    #[test_case("tokyo".to_string(), 0 => Err(Error::InputTooLong { len: 5, max: 0 }) ; "zero limit")]
+    fn find_lenient_length_tests(s: String, max_len: usize) -> Result<Prefecture, Error> {
+        find_lenient_with_max_len(s, max_len)
+    }
+
+    #[cfg(feature = "kana")]
+    #[test]
+    fn fold_kana_is_unambiguous() {
+        let mut seen = HashMap::new();
+        for pref in Prefecture::VARIANTS {
+            let names = pref.names();
+            for name in [names.hiragana, names.hiragana_short] {
+                if let Some(other) = seen.insert(fold_kana(name), pref) {
+                    assert_eq!(other, pref, "{} is ambiguous", name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn from_code_tests() {
+        for pref in Prefecture::VARIANTS {
+            assert_eq!(
+                Prefecture::from_code(pref.jis_x_0401_code() as u8),
+                Some(pref)
+            );
+        }
+        assert_eq!(Prefecture::from_code(0), None);
+        assert_eq!(Prefecture::from_code(48), None);
+        assert_eq!(Prefecture::from_code(u8::MAX), None);
+    }
+
+    #[test]
+    fn group_by_region_tests() {
+        let groups: Vec<(Region, &[Prefecture])> = group_by_region().collect();
+        assert_eq!(groups.len(), Region::COUNT);
+        for (region, prefectures) in groups {
+            assert!(prefectures.iter().all(|pref| pref.region() == region));
+            assert!(prefectures
+                .windows(2)
+                .all(|pair| pair[0].jis_x_0401_code() < pair[1].jis_x_0401_code()));
+        }
+    }
+
+    #[test]
+    fn iter_by_region_tests() {
+        let prefectures: Vec<Prefecture> = iter_by_region().collect();
+        assert_eq!(prefectures.len(), Prefecture::COUNT);
+        assert!(prefectures
+            .windows(2)
+            .all(|pair| pair[0].region() as u8 <= pair[1].region() as u8));
+    }
+
+    #[cfg(all(feature = "kana", feature = "english"))]
+    #[test]
+    fn data_tests() {
+        for pref in Prefecture::VARIANTS {
+            let data = pref.data();
+            assert_eq!(data.prefecture, pref);
+            assert_eq!(data.kanji, pref.kanji());
+            assert_eq!(data.hiragana, pref.hiragana());
+            assert_eq!(data.katakana, pref.katakana());
+            assert_eq!(data.english, pref.english());
+            assert_eq!(data.kunrei, pref.kunrei());
+        }
+    }
+
+    #[test_case("東京都と大阪府" => vec![(Prefecture::Tokyo, 0..9), (Prefecture::Osaka, 12..21)]; "long names")]
+    #[test_case("京都府" => vec![(Prefecture::Kyoto, 0..9)]; "kyoto")]
+    #[test_case("東京都" => vec![(Prefecture::Tokyo, 0..9)]; "tokyo is not kyoto")]
+    #[test_case("東京で" => vec![(Prefecture::Tokyo, 0..6)]; "short name")]
+    #[test_case("北海道札幌市" => vec![(Prefecture::Hokkaido, 0..9)]; "hokkaido")]
+    #[test_case("神奈川県横浜市と神奈川" => vec![(Prefecture::Kanagawa, 0..12), (Prefecture::Kanagawa, 24..33)]; "repeated")]
+    #[test_case("tokyo とうきょう" => Vec::new(); "kana and english")]
+    #[test_case("" => Vec::new(); "empty")]
+    fn extract_all_tests(text: &str) -> Vec<(Prefecture, Range<usize>)> {
+        extract_all(text)
+    }
+
+    #[test]
+    fn iso_code_tests() {
+        for pref in Prefecture::VARIANTS {
+            assert_eq!(find_by_iso_code(pref.iso_code()), Ok(pref));
+        }
+    }
+
+    #[test_case("JP-13" => Ok(Prefecture::Tokyo))]
+    #[test_case("jp-47" => Ok(Prefecture::Okinawa))]
+    #[test_case("JP-00" => Err(Error::InvalidIsoCode { code: "JP-00".to_string() }))]
+    #[test_case("JP-48" => Err(Error::InvalidIsoCode { code: "JP-48".to_string() }))]
+    #[test_case("JP-013" => Err(Error::InvalidIsoCode { code: "JP-013".to_string() }))]
+    #[test_case("JP-+1" => Err(Error::InvalidIsoCode { code: "JP-+1".to_string() }))]
+    #[test_case("JP13" => Err(Error::InvalidIsoCode { code: "JP13".to_string() }))]
+    #[test_case("13" => Err(Error::InvalidIsoCode { code: "13".to_string() }))]
+    #[test_case("東京" => Err(Error::InvalidIsoCode { code: "東京".to_string() }); "kanji")]
+    #[test_case("" => Err(Error::InvalidIsoCode { code: "".to_string() }))]
+    fn find_by_iso_code_tests(code: &str) -> Result<Prefecture, Error> {
+        find_by_iso_code(code)
+    }
+
+    #[test]
+    fn codes_tests() {
+        let codes: Vec<u32> = Prefecture::VARIANTS
+            .iter()
+            .map(Prefecture::jis_x_0401_code)
+            .collect();
+        assert_eq!(codes, Prefecture::CODES);
+        assert!(Prefecture::iter_codes().eq(codes));
+    }
+
+    #[test]
+    fn variants_tests() {
+        assert_eq!(Prefecture::VARIANTS.len(), Prefecture::COUNT);
+        Prefecture::VARIANTS
+            .iter()
+            .enumerate()
+            .for_each(|(i, pref)| assert_eq!(pref.jis_x_0401_code() as usize, i + 1));
+    }
+
+    #[cfg(feature = "i18n")]
+    #[test_case(Prefecture::Hokkaido => String::from("北海道"))]
+    #[test_case(Prefecture::Aomori => String::from("青森县"))]
+    #[test_case(Prefecture::Iwate => String::from("岩手县"))]
+    #[test_case(Prefecture::Miyagi => String::from("宫城县"))]
+    #[test_case(Prefecture::Akita => String::from("秋田县"))]
+    #[test_case(Prefecture::Yamagata => String::from("山形县"))]
+    #[test_case(Prefecture::Fukushima => String::from("福岛县"))]
+    #[test_case(Prefecture::Ibaraki => String::from("茨城县"))]
+    #[test_case(Prefecture::Tochigi => String::from("栃木县"))]
+    #[test_case(Prefecture::Gunma => String::from("群马县"))]
+    #[test_case(Prefecture::Saitama => String::from("埼玉县"))]
+    #[test_case(Prefecture::Chiba => String::from("千叶县"))]
+    #[test_case(Prefecture::Tokyo => String::from("东京都"))]
+    #[test_case(Prefecture::Kanagawa => String::from("神奈川县"))]
+    #[test_case(Prefecture::Niigata => String::from("新潟县"))]
+    #[test_case(Prefecture::Toyama => String::from("富山县"))]
+    #[test_case(Prefecture::Ishikawa => String::from("石川县"))]
+    #[test_case(Prefecture::Fukui => String::from("福井县"))]
+    #[test_case(Prefecture::Yamanashi => String::from("山梨县"))]
+    #[test_case(Prefecture::Nagano => String::from("长野县"))]
+    #[test_case(Prefecture::Gifu => String::from("岐阜县"))]
+    #[test_case(Prefecture::Shizuoka => String::from("静冈县"))]
+    #[test_case(Prefecture::Aichi => String::from("爱知县"))]
+    #[test_case(Prefecture::Mie => String::from("三重县"))]
+    #[test_case(Prefecture::Shiga => String::from("滋贺县"))]
+    #[test_case(Prefecture::Kyoto => String::from("京都府"))]
+    #[test_case(Prefecture::Osaka => String::from("大阪府"))]
+    #[test_case(Prefecture::Hyogo => String::from("兵库县"))]
+    #[test_case(Prefecture::Nara => String::from("奈良县"))]
+    #[test_case(Prefecture::Wakayama => String::from("和歌山县"))]
+    #[test_case(Prefecture::Tottori => String::from("鸟取县"))]
+    #[test_case(Prefecture::Shimane => String::from("岛根县"))]
+    #[test_case(Prefecture::Okayama => String::from("冈山县"))]
+    #[test_case(Prefecture::Hiroshima => String::from("广岛县"))]
+    #[test_case(Prefecture::Yamaguchi => String::from("山口县"))]
+    #[test_case(Prefecture::Tokushima => String::from("德岛县"))]
+    #[test_case(Prefecture::Kagawa => String::from("香川县"))]
+    #[test_case(Prefecture::Ehime => String::from("爱媛县"))]
+    #[test_case(Prefecture::Kochi => String::from("高知县"))]
+    #[test_case(Prefecture::Fukuoka => String::from("福冈县"))]
+    #[test_case(Prefecture::Saga => String::from("佐贺县"))]
+    #[test_case(Prefecture::Nagasaki => String::from("长崎县"))]
+    #[test_case(Prefecture::Kumamoto => String::from("熊本县"))]
+    #[test_case(Prefecture::Oita => String::from("大分县"))]
+    #[test_case(Prefecture::Miyazaki => String::from("宫崎县"))]
+    #[test_case(Prefecture::Kagoshima => String::from("鹿儿岛县"))]
+    #[test_case(Prefecture::Okinawa => String::from("冲绳县"))]
+    fn name_zh_hans_tests(prefecture: Prefecture) -> String {
+        prefecture.name_zh_hans()
+    }
+
+    #[cfg(feature = "i18n")]
+    #[test_case(Prefecture::Hokkaido => String::from("北海道"))]
+    #[test_case(Prefecture::Aomori => String::from("青森縣"))]
+    #[test_case(Prefecture::Iwate => String::from("岩手縣"))]
+    #[test_case(Prefecture::Miyagi => String::from("宮城縣"))]
+    #[test_case(Prefecture::Akita => String::from("秋田縣"))]
+    #[test_case(Prefecture::Yamagata => String::from("山形縣"))]
+    #[test_case(Prefecture::Fukushima => String::from("福島縣"))]
+    #[test_case(Prefecture::Ibaraki => String::from("茨城縣"))]
+    #[test_case(Prefecture::Tochigi => String::from("栃木縣"))]
+    #[test_case(Prefecture::Gunma => String::from("群馬縣"))]
+    #[test_case(Prefecture::Saitama => String::from("埼玉縣"))]
+    #[test_case(Prefecture::Chiba => String::from("千葉縣"))]
+    #[test_case(Prefecture::Tokyo => String::from("東京都"))]
+    #[test_case(Prefecture::Kanagawa => String::from("神奈川縣"))]
+    #[test_case(Prefecture::Niigata => String::from("新潟縣"))]
+    #[test_case(Prefecture::Toyama => String::from("富山縣"))]
+    #[test_case(Prefecture::Ishikawa => String::from("石川縣"))]
+    #[test_case(Prefecture::Fukui => String::from("福井縣"))]
+    #[test_case(Prefecture::Yamanashi => String::from("山梨縣"))]
+    #[test_case(Prefecture::Nagano => String::from("長野縣"))]
+    #[test_case(Prefecture::Gifu => String::from("岐阜縣"))]
+    #[test_case(Prefecture::Shizuoka => String::from("靜岡縣"))]
+    #[test_case(Prefecture::Aichi => String::from("愛知縣"))]
+    #[test_case(Prefecture::Mie => String::from("三重縣"))]
+    #[test_case(Prefecture::Shiga => String::from("滋賀縣"))]
+    #[test_case(Prefecture::Kyoto => String::from("京都府"))]
+    #[test_case(Prefecture::Osaka => String::from("大阪府"))]
+    #[test_case(Prefecture::Hyogo => String::from("兵庫縣"))]
+    #[test_case(Prefecture::Nara => String::from("奈良縣"))]
+    #[test_case(Prefecture::Wakayama => String::from("和歌山縣"))]
+    #[test_case(Prefecture::Tottori => String::from("鳥取縣"))]
+    #[test_case(Prefecture::Shimane => String::from("島根縣"))]
+    #[test_case(Prefecture::Okayama => String::from("岡山縣"))]
+    #[test_case(Prefecture::Hiroshima => String::from("廣島縣"))]
+    #[test_case(Prefecture::Yamaguchi => String::from("山口縣"))]
+    #[test_case(Prefecture::Tokushima => String::from("德島縣"))]
+    #[test_case(Prefecture::Kagawa => String::from("香川縣"))]
+    #[test_case(Prefecture::Ehime => String::from("愛媛縣"))]
+    #[test_case(Prefecture::Kochi => String::from("高知縣"))]
+    #[test_case(Prefecture::Fukuoka => String::from("福岡縣"))]
+    #[test_case(Prefecture::Saga => String::from("佐賀縣"))]
+    #[test_case(Prefecture::Nagasaki => String::from("長崎縣"))]
+    #[test_case(Prefecture::Kumamoto => String::from("熊本縣"))]
+    #[test_case(Prefecture::Oita => String::from("大分縣"))]
+    #[test_case(Prefecture::Miyazaki => String::from("宮崎縣"))]
+    #[test_case(Prefecture::Kagoshima => String::from("鹿兒島縣"))]
+    #[test_case(Prefecture::Okinawa => String::from("沖繩縣"))]
+    fn name_zh_hant_tests(prefecture: Prefecture) -> String {
+        prefecture.name_zh_hant()
+    }
+
+    #[cfg(feature = "i18n")]
+    #[test_case(Prefecture::Hokkaido => String::from("홋카이도"))]
+    #[test_case(Prefecture::Aomori => String::from("아오모리현"))]
+    #[test_case(Prefecture::Iwate => String::from("이와테현"))]
+    #[test_case(Prefecture::Miyagi => String::from("미야기현"))]
+    #[test_case(Prefecture::Akita => String::from("아키타현"))]
+    #[test_case(Prefecture::Yamagata => String::from("야마가타현"))]
+    #[test_case(Prefecture::Fukushima => String::from("후쿠시마현"))]
+    #[test_case(Prefecture::Ibaraki => String::from("이바라키현"))]
+    #[test_case(Prefecture::Tochigi => String::from("도치기현"))]
+    #[test_case(Prefecture::Gunma => String::from("군마현"))]
+    #[test_case(Prefecture::Saitama => String::from("사이타마현"))]
+    #[test_case(Prefecture::Chiba => String::from("지바현"))]
+    #[test_case(Prefecture::Tokyo => String::from("도쿄도"))]
+    #[test_case(Prefecture::Kanagawa => String::from("가나가와현"))]
+    #[test_case(Prefecture::Niigata => String::from("니가타현"))]
+    #[test_case(Prefecture::Toyama => String::from("도야마현"))]
+    #[test_case(Prefecture::Ishikawa => String::from("이시카와현"))]
+    #[test_case(Prefecture::Fukui => String::from("후쿠이현"))]
+    #[test_case(Prefecture::Yamanashi => String::from("야마나시현"))]
+    #[test_case(Prefecture::Nagano => String::from("나가노현"))]
+    #[test_case(Prefecture::Gifu => String::from("기후현"))]
+    #[test_case(Prefecture::Shizuoka => String::from("시즈오카현"))]
+    #[test_case(Prefecture::Aichi => String::from("아이치현"))]
+    #[test_case(Prefecture::Mie => String::from("미에현"))]
+    #[test_case(Prefecture::Shiga => String::from("시가현"))]
+    #[test_case(Prefecture::Kyoto => String::from("교토부"))]
+    #[test_case(Prefecture::Osaka => String::from("오사카부"))]
+    #[test_case(Prefecture::Hyogo => String::from("효고현"))]
+    #[test_case(Prefecture::Nara => String::from("나라현"))]
+    #[test_case(Prefecture::Wakayama => String::from("와카야마현"))]
+    #[test_case(Prefecture::Tottori => String::from("돗토리현"))]
+    #[test_case(Prefecture::Shimane => String::from("시마네현"))]
+    #[test_case(Prefecture::Okayama => String::from("오카야마현"))]
+    #[test_case(Prefecture::Hiroshima => String::from("히로시마현"))]
+    #[test_case(Prefecture::Yamaguchi => String::from("야마구치현"))]
+    #[test_case(Prefecture::Tokushima => String::from("도쿠시마현"))]
+    #[test_case(Prefecture::Kagawa => String::from("가가와현"))]
+    #[test_case(Prefecture::Ehime => String::from("에히메현"))]
+    #[test_case(Prefecture::Kochi => String::from("고치현"))]
+    #[test_case(Prefecture::Fukuoka => String::from("후쿠오카현"))]
+    #[test_case(Prefecture::Saga => String::from("사가현"))]
+    #[test_case(Prefecture::Nagasaki => String::from("나가사키현"))]
+    #[test_case(Prefecture::Kumamoto => String::from("구마모토현"))]
+    #[test_case(Prefecture::Oita => String::from("오이타현"))]
+    #[test_case(Prefecture::Miyazaki => String::from("미야자키현"))]
+    #[test_case(Prefecture::Kagoshima => String::from("가고시마현"))]
+    #[test_case(Prefecture::Okinawa => String::from("오키나와현"))]
+    fn name_ko_tests(prefecture: Prefecture) -> String {
+        prefecture.name_ko()
+    }
+
+    #[cfg(feature = "i18n")]
+    #[test]
+    fn find_by_localized_tests() {
+        Prefecture::VARIANTS.iter().for_each(|pref| {
+            assert_eq!(find_by_zh_hans(pref.name_zh_hans()), Ok(*pref));
+            assert_eq!(find_by_zh_hant(pref.name_zh_hant()), Ok(*pref));
+            assert_eq!(find_by_ko(pref.name_ko()), Ok(*pref));
+        });
+    }
+
+    #[cfg(feature = "i18n")]
+    #[test_case("北海道" => Ok(Prefecture::Hokkaido))]
+    #[test_case("神奈川" => Ok(Prefecture::Kanagawa))]
+    #[test_case("大阪" => Ok(Prefecture::Osaka))]
+    #[test_case("冲绳" => Ok(Prefecture::Okinawa))]
+    #[test_case("沖繩" => Err(Error::InvalidPrefectureName { name: "沖繩".to_string() }))]
+    fn find_by_zh_hans_tests(zh_hans: &str) -> Result<Prefecture, Error> {
+        find_by_zh_hans(zh_hans)
+    }
+
+    #[cfg(feature = "i18n")]
+    #[test_case("北海道" => Ok(Prefecture::Hokkaido))]
+    #[test_case("廣島" => Ok(Prefecture::Hiroshima))]
+    #[test_case("京都" => Ok(Prefecture::Kyoto))]
+    #[test_case("沖繩" => Ok(Prefecture::Okinawa))]
+    #[test_case("冲绳" => Err(Error::InvalidPrefectureName { name: "冲绳".to_string() }))]
+    fn find_by_zh_hant_tests(zh_hant: &str) -> Result<Prefecture, Error> {
+        find_by_zh_hant(zh_hant)
+    }
+
+    #[cfg(feature = "i18n")]
+    #[test_case("홋카이도" => Ok(Prefecture::Hokkaido))]
+    #[test_case("홋카이" => Err(Error::InvalidPrefectureName { name: "홋카이".to_string() }))]
+    #[test_case("오사카" => Ok(Prefecture::Osaka))]
+    #[test_case("오키나와" => Ok(Prefecture::Okinawa))]
+    #[test_case("東京都" => Err(Error::InvalidPrefectureName { name: "東京都".to_string() }))]
+    fn find_by_ko_tests(ko: &str) -> Result<Prefecture, Error> {
+        find_by_ko(ko)
+    }
+
+    #[test_case(SortOrder::Jis)]
+    #[test_case(SortOrder::NorthToSouthGeographic)]
+    #[test_case(SortOrder::GojuonReading)]
+    fn ordering_is_permutation_tests(ordering: SortOrder) {
+        let mut prefectures = ordering.prefectures().to_vec();
+        prefectures.sort_by_key(Prefecture::jis_x_0401_code);
+        assert_eq!(prefectures, Prefecture::VARIANTS);
+        ordering
+            .prefectures()
+            .iter()
+            .enumerate()
+            .for_each(|(i, pref)| assert_eq!(ordering.sort_index(*pref), i));
+    }
+
+    #[cfg(feature = "kana")]
+    #[test]
+    fn gojuon_reading_tests() {
+        let readings: Vec<String> = GOJUON_ORDER
+            .iter()
+            .map(|pref| pref.hiragana_short())
+            .collect();
+        assert_eq!(readings[..4], ["あいち", "あおもり", "あきた", "いしかわ"]);
+        assert_eq!(readings[14..16], ["ぎふ", "きょうと"]);
+        assert_eq!(readings[46], "わかやま");
+    }
+
+    #[test]
+    fn north_to_south_tests() {
+        let prefectures = SortOrder::NorthToSouthGeographic.prefectures();
+        assert_eq!(prefectures[0], Prefecture::Hokkaido);
+        assert_eq!(
+            prefectures[45..],
+            [Prefecture::Kagoshima, Prefecture::Okinawa]
+        );
+    }
+
+    #[test_case(SortOrder::Jis, Prefecture::Hokkaido, Prefecture::Aichi => cmp::Ordering::Less)]
+    #[test_case(SortOrder::GojuonReading, Prefecture::Hokkaido, Prefecture::Aichi => cmp::Ordering::Greater)]
+    #[test_case(SortOrder::GojuonReading, Prefecture::Tokyo, Prefecture::Tokyo => cmp::Ordering::Equal)]
+    #[test_case(SortOrder::NorthToSouthGeographic, Prefecture::Niigata, Prefecture::Fukushima => cmp::Ordering::Less)]
+    #[test_case(SortOrder::NorthToSouthGeographic, Prefecture::Kagoshima, Prefecture::Okinawa => cmp::Ordering::Less)]
+    #[test_case(SortOrder::NorthToSouthGeographic, Prefecture::Okinawa, Prefecture::Hokkaido => cmp::Ordering::Greater)]
+    fn ordering_compare_tests(ordering: SortOrder, a: Prefecture, b: Prefecture) -> cmp::Ordering {
+        ordering.compare(a, b)
+    }
+
+    #[test_case("" ; "empty")]
+    #[test_case("\0" ; "nul")]
+    #[test_case("東\u{3099}京都" ; "combining mark")]
+    #[test_case("🇯🇵東京都👨‍👩‍👧" ; "emoji sequences")]
+    #[test_case("\u{FEFF}tokyo\u{200D}" ; "invisible characters")]
+    #[test_case("İSTANBUL ǅ ß" ; "case mapping changing length")]
+    #[test_case(&"東".repeat(1000) ; "long input")]
+    fn adversarial_input_tests(s: &str) {
+        let _ = find(s);
+        let _ = Prefecture::from_str(s);
+        let _ = find_by_kanji(s);
+        #[cfg(feature = "kana")]
+        let _ = find_by_hiragana(s);
+        #[cfg(feature = "kana")]
+        let _ = find_by_katakana(s);
+        #[cfg(feature = "english")]
+        let _ = find_by_english(s);
+        let _ = find_all([s, s]);
+        let _ = search_prefix(s);
+        let _ = search_fuzzy(s);
+        #[cfg(feature = "kana")]
+        let _ = find_lenient(s);
+        let _ = extract_all(s);
+        let _ = parse(s);
+        let _ = find_by_iso_code(s);
+        let _ = cmp_by_code(s, s);
+    }
 }
@@ -0,0 +1,216 @@
+//! the eight traditional regions (八地方区分) of Japan
+//!
+//! Okinawa is counted as part of Kyushu and Mie as part of Kinki, as in the division
+//! taught in schools.
+//!
+//! # Examples
+//!
+//! ```
+//! use jp_prefecture::prefectures::Prefecture;
+//! use jp_prefecture::regions::Region;
+//!
+//! let region = Prefecture::Mie.region();
+//!
+//! assert_eq!(region, Region::Kinki);
+//! assert_eq!(region.kanji(), "近畿");
+//! assert_eq!(Region::Shikoku.prefectures().len(), 4);
+//! ```
+
+use crate::mapping::{RegionData, REGION_TABLE};
+use crate::prefectures::Prefecture;
+
+/// A value of region
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum Region {
+    Hokkaido,
+    Tohoku,
+    Kanto,
+    Chubu,
+    Kinki,
+    Chugoku,
+    Shikoku,
+    Kyushu,
+}
+
+impl Region {
+    /// Number of regions
+    pub const COUNT: usize = 8;
+
+    /// All regions, from Hokkaido to Kyushu
+    pub const VARIANTS: [Region; Region::COUNT] = [
+        Region::Hokkaido,
+        Region::Tohoku,
+        Region::Kanto,
+        Region::Chubu,
+        Region::Kinki,
+        Region::Chugoku,
+        Region::Shikoku,
+        Region::Kyushu,
+    ];
+
+    /// Return the prefectures in a region, ordered by JIS X 0401 code
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    /// use jp_prefecture::regions::Region;
+    ///
+    /// assert_eq!(Region::Hokkaido.prefectures(), &[Prefecture::Hokkaido]);
+    /// ```
+    pub fn prefectures(&self) -> &'static [Prefecture] {
+        self.data().prefectures
+    }
+
+    /// Return a region name in kanji
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::regions::Region;
+    ///
+    /// assert_eq!(Region::Chugoku.kanji(), "中国".to_string());
+    /// ```
+    pub fn kanji(&self) -> String {
+        self.data().kanji.to_string()
+    }
+
+    /// Return a region name in hiragana
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::regions::Region;
+    ///
+    /// assert_eq!(Region::Chugoku.hiragana(), "ちゅうごく".to_string());
+    /// ```
+    #[cfg(feature = "kana")]
+    pub fn hiragana(&self) -> String {
+        self.data().hiragana.to_string()
+    }
+
+    /// Return a region name in katakana
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::regions::Region;
+    ///
+    /// assert_eq!(Region::Chugoku.katakana(), "チュウゴク".to_string());
+    /// ```
+    #[cfg(feature = "kana")]
+    pub fn katakana(&self) -> String {
+        self.data().katakana.to_string()
+    }
+
+    /// Return a region name in english
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::regions::Region;
+    ///
+    /// assert_eq!(Region::Chugoku.english(), "Chugoku".to_string());
+    /// ```
+    #[cfg(feature = "english")]
+    pub fn english(&self) -> String {
+        self.data().english.to_string()
+    }
+
+    fn data(&self) -> &'static RegionData {
+        REGION_TABLE
+            .iter()
+            .find(|data| data.region == *self)
+            .expect("Unexpected error")
+    }
+}
+
+impl Prefecture {
+    /// Return the region a prefecture belongs to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jp_prefecture::prefectures::Prefecture;
+    /// use jp_prefecture::regions::Region;
+    ///
+    /// assert_eq!(Prefecture::Yamanashi.region(), Region::Chubu);
+    /// assert_eq!(Prefecture::Okinawa.region(), Region::Kyushu);
+    /// ```
+    pub fn region(&self) -> Region {
+        REGION_TABLE
+            .iter()
+            .find(|data| data.prefectures.contains(self))
+            .map(|data| data.region)
+            .expect("Unexpected error")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Prefecture::Hokkaido => Region::Hokkaido)]
+    #[test_case(Prefecture::Aomori => Region::Tohoku)]
+    #[test_case(Prefecture::Iwate => Region::Tohoku)]
+    #[test_case(Prefecture::Miyagi => Region::Tohoku)]
+    #[test_case(Prefecture::Akita => Region::Tohoku)]
+    #[test_case(Prefecture::Yamagata => Region::Tohoku)]
+    #[test_case(Prefecture::Fukushima => Region::Tohoku)]
+    #[test_case(Prefecture::Ibaraki => Region::Kanto)]
+    #[test_case(Prefecture::Tochigi => Region::Kanto)]
+    #[test_case(Prefecture::Gunma => Region::Kanto)]
+    #[test_case(Prefecture::Saitama => Region::Kanto)]
+    #[test_case(Prefecture::Chiba => Region::Kanto)]
+    #[test_case(Prefecture::Tokyo => Region::Kanto)]
+    #[test_case(Prefecture::Kanagawa => Region::Kanto)]
+    #[test_case(Prefecture::Niigata => Region::Chubu)]
+    #[test_case(Prefecture::Toyama => Region::Chubu)]
+    #[test_case(Prefecture::Ishikawa => Region::Chubu)]
+    #[test_case(Prefecture::Fukui => Region::Chubu)]
+    #[test_case(Prefecture::Yamanashi => Region::Chubu)]
+    #[test_case(Prefecture::Nagano => Region::Chubu)]
+    #[test_case(Prefecture::Gifu => Region::Chubu)]
+    #[test_case(Prefecture::Shizuoka => Region::Chubu)]
+    #[test_case(Prefecture::Aichi => Region::Chubu)]
+    #[test_case(Prefecture::Mie => Region::Kinki)]
+    #[test_case(Prefecture::Shiga => Region::Kinki)]
+    #[test_case(Prefecture::Kyoto => Region::Kinki)]
+    #[test_case(Prefecture::Osaka => Region::Kinki)]
+    #[test_case(Prefecture::Hyogo => Region::Kinki)]
+    #[test_case(Prefecture::Nara => Region::Kinki)]
+    #[test_case(Prefecture::Wakayama => Region::Kinki)]
+    #[test_case(Prefecture::Tottori => Region::Chugoku)]
+    #[test_case(Prefecture::Shimane => Region::Chugoku)]
+    #[test_case(Prefecture::Okayama => Region::Chugoku)]
+    #[test_case(Prefecture::Hiroshima => Region::Chugoku)]
+    #[test_case(Prefecture::Yamaguchi => Region::Chugoku)]
+    #[test_case(Prefecture::Tokushima => Region::Shikoku)]
+    #[test_case(Prefecture::Kagawa => Region::Shikoku)]
+    #[test_case(Prefecture::Ehime => Region::Shikoku)]
+    #[test_case(Prefecture::Kochi => Region::Shikoku)]
+    #[test_case(Prefecture::Fukuoka => Region::Kyushu)]
+    #[test_case(Prefecture::Saga => Region::Kyushu)]
+    #[test_case(Prefecture::Nagasaki => Region::Kyushu)]
+    #[test_case(Prefecture::Kumamoto => Region::Kyushu)]
+    #[test_case(Prefecture::Oita => Region::Kyushu)]
+    #[test_case(Prefecture::Miyazaki => Region::Kyushu)]
+    #[test_case(Prefecture::Kagoshima => Region::Kyushu)]
+    #[test_case(Prefecture::Okinawa => Region::Kyushu)]
+    fn region_tests(prefecture: Prefecture) -> Region {
+        prefecture.region()
+    }
+
+    #[test]
+    fn prefectures_tests() {
+        let mut prefectures: Vec<Prefecture> = Region::VARIANTS
+            .iter()
+            .flat_map(|region| region.prefectures().iter().copied())
+            .collect();
+        prefectures.sort_by_key(Prefecture::jis_x_0401_code);
+        assert_eq!(prefectures, Prefecture::VARIANTS);
+    }
+}
@@ -0,0 +1,320 @@
+//! Field helpers for choosing the wire format of a prefecture
+//!
+//! `Prefecture` itself serializes as its variant name (e.g. `"Tokyo"`). The modules here
+//! let individual fields use another representation with `#[serde(with = "...")]`.
+//!
+//! # Examples
+//!
+//! ```
+//! use jp_prefecture::prefectures::Prefecture;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Office {
+//!     #[serde(with = "jp_prefecture::serde::code")]
+//!     location: Prefecture,
+//!     #[serde(with = "jp_prefecture::serde::kanji")]
+//!     head_office: Prefecture,
+//! }
+//!
+//! let office = Office {
+//!     location: Prefecture::Tokyo,
+//!     head_office: Prefecture::Osaka,
+//! };
+//! let json = serde_json::to_string(&office).unwrap();
+//!
+//! assert_eq!(json, r#"{"location":13,"head_office":"大阪府"}"#);
+//! assert_eq!(serde_json::from_str::<Office>(&json).unwrap(), office);
+//! ```
+
+/// (De)serialize a prefecture as its JIS X 0401 code (e.g. `13`)
+pub mod code {
+    use ::serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+    use crate::prefectures::{self, Prefecture};
+
+    pub fn serialize<S: Serializer>(
+        prefecture: &Prefecture,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(prefecture.jis_x_0401_code())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Prefecture, D::Error> {
+        let code = u32::deserialize(deserializer)?;
+        prefectures::find_by_code(code).map_err(D::Error::custom)
+    }
+}
+
+/// (De)serialize a prefecture as its name in kanji (e.g. `"東京都"`)
+///
+/// The short name (e.g. `"東京"`) is also accepted when deserializing.
+pub mod kanji {
+    use ::serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+    use crate::prefectures::{self, Prefecture};
+
+    pub fn serialize<S: Serializer>(
+        prefecture: &Prefecture,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(prefecture.names().kanji)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Prefecture, D::Error> {
+        let kanji = String::deserialize(deserializer)?;
+        prefectures::find_by_kanji(kanji).map_err(D::Error::custom)
+    }
+}
+
+/// (De)serialize a prefecture as its name in english (e.g. `"Tokyo"`)
+///
+/// The name is matched case-insensitively when deserializing.
+#[cfg(feature = "english")]
+pub mod english {
+    use ::serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+    use crate::prefectures::{self, Prefecture};
+
+    pub fn serialize<S: Serializer>(
+        prefecture: &Prefecture,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(prefecture.names().english)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Prefecture, D::Error> {
+        let english = String::deserialize(deserializer)?;
+        prefectures::find_by_english(english).map_err(D::Error::custom)
+    }
+}
+
+/// Serialize a prefecture as its JIS X 0401 code and deserialize it from any known form
+///
+/// Deserialization accepts an integer code (e.g. `13`), a code in a string (e.g. `"13"`,
+/// `"01"`, `"１３"`), an ISO 3166-2 code (e.g. `"JP-13"`) or any name form accepted by
+/// [`find`](crate::prefectures::find), plus the romaji spellings accepted by `find_by_english`
+/// when the `english` feature is enabled.
+///
+/// The input type is only known once it is read, so deserialization goes through
+/// `Deserializer::deserialize_any`. Self-describing formats such as JSON support it, but
+/// formats like bincode or postcard do not and fail at runtime; use [`code`](super::code)
+/// with them instead.
+pub mod flexible {
+    use std::fmt;
+
+    use ::serde::de::{self, Unexpected, Visitor};
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::prefectures::{self, Prefecture};
+    use crate::Error;
+
+    pub fn serialize<S: Serializer>(
+        prefecture: &Prefecture,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::code::serialize(prefecture, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Prefecture, D::Error> {
+        deserializer.deserialize_any(FlexibleVisitor)
+    }
+
+    struct FlexibleVisitor;
+
+    impl<'de> Visitor<'de> for FlexibleVisitor {
+        type Value = Prefecture;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a prefecture code or name")
+        }
+
+        fn visit_u64<E: de::Error>(self, code: u64) -> Result<Prefecture, E> {
+            u32::try_from(code)
+                .ok()
+                .and_then(|code| prefectures::find_by_code(code).ok())
+                .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(code), &self))
+        }
+
+        fn visit_i64<E: de::Error>(self, code: i64) -> Result<Prefecture, E> {
+            u64::try_from(code)
+                .map_err(|_| E::invalid_value(Unexpected::Signed(code), &self))
+                .and_then(|code| self.visit_u64(code))
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<Prefecture, E> {
+            find(s).map_err(E::custom)
+        }
+    }
+
+    fn find(s: &str) -> Result<Prefecture, Error> {
+        if s.get(..3)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("JP-"))
+        {
+            return prefectures::find_by_iso_code(s);
+        }
+        if s.chars()
+            .any(|c| c.is_ascii_digit() || ('０'..='９').contains(&c))
+        {
+            return prefectures::find_by_code_str(s);
+        }
+        match prefectures::find(s) {
+            #[cfg(feature = "english")]
+            Err(_) => prefectures::find_by_english(s),
+            found => found,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+    use test_case::test_case;
+
+    use crate::prefectures::Prefecture;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Fields {
+        #[serde(with = "super::code")]
+        code: Prefecture,
+        #[serde(with = "super::kanji")]
+        kanji: Prefecture,
+        plain: Prefecture,
+    }
+
+    #[test]
+    fn serialize_tests() {
+        let fields = Fields {
+            code: Prefecture::Hokkaido,
+            kanji: Prefecture::Tokyo,
+            plain: Prefecture::Okinawa,
+        };
+        assert_eq!(
+            serde_json::to_string(&fields).unwrap(),
+            r#"{"code":1,"kanji":"東京都","plain":"Okinawa"}"#
+        );
+    }
+
+    #[test_case(r#"{"code":1,"kanji":"東京都","plain":"Okinawa"}"# => true)]
+    #[test_case(r#"{"code":1,"kanji":"東京","plain":"Okinawa"}"# => true)]
+    #[test_case(r#"{"code":48,"kanji":"東京都","plain":"Okinawa"}"# => false)]
+    #[test_case(r#"{"code":"1","kanji":"東京都","plain":"Okinawa"}"# => false)]
+    #[test_case(r#"{"code":1,"kanji":"東京県","plain":"Okinawa"}"# => false)]
+    fn deserialize_tests(json: &str) -> bool {
+        let expected = Fields {
+            code: Prefecture::Hokkaido,
+            kanji: Prefecture::Tokyo,
+            plain: Prefecture::Okinawa,
+        };
+        serde_json::from_str::<Fields>(json).is_ok_and(|fields| fields == expected)
+    }
+
+    #[cfg(feature = "english")]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct English {
+        #[serde(with = "super::english")]
+        prefecture: Prefecture,
+    }
+
+    #[cfg(feature = "english")]
+    #[test]
+    fn english_serialize_tests() {
+        let english = English {
+            prefecture: Prefecture::Osaka,
+        };
+        assert_eq!(
+            serde_json::to_string(&english).unwrap(),
+            r#"{"prefecture":"Osaka"}"#
+        );
+    }
+
+    #[cfg(feature = "english")]
+    #[test_case(r#""Osaka""# => Some(Prefecture::Osaka))]
+    #[test_case(r#""osaka""# => Some(Prefecture::Osaka) ; "lower case")]
+    #[test_case(r#""Osaka~""# => None ; "invalid name")]
+    fn english_deserialize_tests(value: &str) -> Option<Prefecture> {
+        serde_json::from_str::<English>(&format!(r#"{{"prefecture":{}}}"#, value))
+            .ok()
+            .map(|english| english.prefecture)
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Flexible {
+        #[serde(with = "super::flexible")]
+        prefecture: Prefecture,
+    }
+
+    #[test]
+    fn flexible_serialize_tests() {
+        let flexible = Flexible {
+            prefecture: Prefecture::Tokyo,
+        };
+        assert_eq!(
+            serde_json::to_string(&flexible).unwrap(),
+            r#"{"prefecture":13}"#
+        );
+    }
+
+    #[test]
+    fn flexible_requires_self_describing_format() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Code {
+            #[serde(with = "super::code")]
+            prefecture: Prefecture,
+        }
+
+        let bytes = bincode::serialize(&Flexible {
+            prefecture: Prefecture::Tokyo,
+        })
+        .unwrap();
+        let err = bincode::deserialize::<Flexible>(&bytes).unwrap_err();
+        assert!(err.to_string().contains("deserialize_any"), "{}", err);
+        assert_eq!(
+            bincode::deserialize::<Code>(&bytes).unwrap(),
+            Code {
+                prefecture: Prefecture::Tokyo
+            }
+        );
+    }
+
+    fn flexible_deserialize(value: &str) -> Option<Prefecture> {
+        serde_json::from_str::<Flexible>(&format!(r#"{{"prefecture":{}}}"#, value))
+            .ok()
+            .map(|flexible| flexible.prefecture)
+    }
+
+    #[test_case("13" => Some(Prefecture::Tokyo))]
+    #[test_case("1" => Some(Prefecture::Hokkaido))]
+    #[test_case(r#""13""# => Some(Prefecture::Tokyo) ; "code in string")]
+    #[test_case(r#""01""# => Some(Prefecture::Hokkaido) ; "zero padded code")]
+    #[test_case(r#""１３""# => Some(Prefecture::Tokyo) ; "full width code")]
+    #[test_case(r#""JP-13""# => Some(Prefecture::Tokyo) ; "iso code")]
+    #[test_case(r#""jp-01""# => Some(Prefecture::Hokkaido) ; "lower case iso code")]
+    #[test_case(r#""東京都""# => Some(Prefecture::Tokyo) ; "kanji")]
+    #[test_case(r#""東京""# => Some(Prefecture::Tokyo) ; "short kanji")]
+    #[test_case("48" => None)]
+    #[test_case("-1" => None)]
+    #[test_case("1.5" => None)]
+    #[test_case("null" => None)]
+    #[test_case(r#""JP-48""# => None ; "invalid iso code")]
+    #[test_case(r#""JP-""# => None ; "empty iso code")]
+    #[test_case(r#""東京県""# => None ; "invalid name")]
+    #[test_case(r#""""# => None ; "empty")]
+    fn flexible_deserialize_tests(value: &str) -> Option<Prefecture> {
+        flexible_deserialize(value)
+    }
+
+    #[cfg(feature = "kana")]
+    #[test_case(r#""とうきょう""# => Some(Prefecture::Tokyo) ; "hiragana")]
+    #[test_case(r#""トウキョウト""# => Some(Prefecture::Tokyo) ; "katakana")]
+    fn flexible_deserialize_tests_from_kana(value: &str) -> Option<Prefecture> {
+        flexible_deserialize(value)
+    }
+
+    #[cfg(feature = "english")]
+    #[test_case(r#""Tokyo""# => Some(Prefecture::Tokyo) ; "english")]
+    #[test_case(r#""Kôti""# => Some(Prefecture::Kochi) ; "kunrei")]
+    fn flexible_deserialize_tests_from_english(value: &str) -> Option<Prefecture> {
+        flexible_deserialize(value)
+    }
+}